@@ -0,0 +1,106 @@
+use std::{env, net::SocketAddr};
+
+use axum::{
+    Json, Router,
+    extract::Path,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use chrono::NaiveDate;
+use dotenvy::dotenv;
+use engine::excel::{
+    error::Error, get_holidays, options::TimesheetOptions, settings::CalendarSource,
+};
+use serde::Serialize;
+
+#[tokio::main]
+async fn main() {
+    // Load logs
+    dotenv().ok();
+    pretty_env_logger::init();
+    // Load envs
+    log::info!("Загрузка env...");
+    let port = env::var("TGEN_API_PORT").unwrap_or_else(|_| {
+        log::error!("Порт не указан! Используем 8080!");
+        "8080".to_string()
+    });
+    let addr = SocketAddr::from(([0, 0, 0, 0], port.parse::<u16>().unwrap()));
+    let router = Router::new()
+        .route("/timesheet", post(timesheet))
+        .route("/calendar/{year}", get(calendar));
+    log::info!("Запуск API...");
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .expect("Не удалось занять порт!");
+    axum::serve(listener, router)
+        .await
+        .expect("Не удалось запустить API!");
+}
+
+#[derive(Serialize)]
+struct CalendarResponse {
+    year: i32,
+    holidays: Vec<NaiveDate>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+// Error is returned as JSON with its status
+struct ApiError {
+    status: StatusCode,
+    message: String,
+}
+
+// Calendar failures are upstream ones, clients may retry them later
+impl From<Error> for ApiError {
+    fn from(err: Error) -> Self {
+        let status = match &err {
+            Error::InvalidOptions(_) | Error::InvalidTable(_) => StatusCode::BAD_REQUEST,
+            Error::NoDataForYear(_) => StatusCode::NOT_FOUND,
+            Error::CalendarFetch(_) | Error::CalendarParse(_) => StatusCode::BAD_GATEWAY,
+            Error::Render(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        if status.is_server_error() {
+            log::error!("Ошибка генерации: {err}");
+        }
+        Self {
+            status,
+            message: err.to_string(),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ErrorResponse {
+            error: self.message,
+        };
+        (self.status, Json(body)).into_response()
+    }
+}
+
+async fn timesheet(Json(options): Json<TimesheetOptions>) -> Result<Response, ApiError> {
+    let format = options.format()?;
+    let table = options.builder()?.build().await?;
+    let file_name = match options.year {
+        Some(year) => format!("tabel_{}.{}", year, format.extension()),
+        None => format!("tabel.{}", format.extension()),
+    };
+    let headers = [
+        (header::CONTENT_TYPE, format.mime_type().to_string()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", file_name),
+        ),
+    ];
+    Ok((headers, table).into_response())
+}
+
+async fn calendar(Path(year): Path<i32>) -> Result<Json<CalendarResponse>, ApiError> {
+    let holidays = get_holidays(year, &CalendarSource::default()).await?;
+    Ok(Json(CalendarResponse { year, holidays }))
+}
@@ -1,112 +1,110 @@
-use crate::excel::data::days::DayType;
+use crate::excel::builder::TimesheetBuilder;
 use crate::excel::data::days::Days;
-use crate::excel::data::days::add_day_cell;
-use crate::excel::data::headers::add_header_cells;
-use crate::excel::data::total::add_total_cells;
+use crate::excel::error::{Error, Result as EResult};
 use crate::excel::holiday::FetchedDates;
-use crate::excel::styles::CellType;
-use crate::excel::styles::DataType;
-use crate::excel::styles::cell_style;
+use crate::excel::settings::{CalendarSource, Period, Settings};
+use crate::excel::team::Employee;
+use crate::excel::timesheet::Timesheet;
 
-use anyhow::Ok;
-use anyhow::Result as AResult;
+use chrono::{Datelike, NaiveDate};
 use network::holiday;
-use rust_xlsxwriter::Format;
-use rust_xlsxwriter::FormatBorder;
-use rust_xlsxwriter::utility::column_name_to_number;
-use rust_xlsxwriter::workbook::Workbook;
 
-pub async fn get_filled_table(salary: u32) -> AResult<Vec<u8>> {
-    // Creating table
-    let mut table = Workbook::new();
-    // Fetch holidays
-    let holidays = FetchedDates::init().await?;
-    // Generate days for filling
-    let days = Days::new_with_holidays(holidays.get_holidays());
-    // Split days to chunks by month
-    let chunks = days.split_months();
-    for month_days in chunks {
-        // Creating Sheet
-        let month_worksheet = table.add_worksheet();
-        // Make worksheet white
-        month_worksheet.set_screen_gridlines(false);
-        // For total formulas
-        let mut weekend_cells = Vec::new();
-        let mut usual_day_cells = Vec::new();
-        // For work hours
-        let mut work_hours: u16 = 0;
-        // Iterate over days in month chunk
-        for day in month_days {
-            // Row number
-            let row_number = 3 + day.number();
-            // Adding day to month sheet and geting this flag
-            let flag = add_day_cell(month_worksheet, day)?;
-            // Creating formula for total block
-            match flag {
-                DayType::Usual => {
-                    usual_day_cells.push(format!("B{}", row_number));
-                    work_hours = work_hours + 8;
-                }
-                _ => {
-                    weekend_cells.push(format!("B{}", row_number));
-                }
-            }
-        }
+// Generate table with default settings, TimesheetBuilder sets the rest
+pub async fn get_filled_table(salary: u32) -> EResult<Vec<u8>> {
+    TimesheetBuilder::new(salary).build().await
+}
 
-        // Adding headers
-        add_header_cells(month_worksheet, month_days.first().unwrap())?;
+pub async fn get_filled_table_with_settings(salary: u32, settings: &Settings) -> EResult<Vec<u8>> {
+    let timesheet = get_timesheet(salary, settings).await?;
+    settings.format.renderer().render(&timesheet, settings)
+}
 
-        // Make formula string
-        let weekends_formula = format!("={}", weekend_cells.join("+"));
-        let usual_days_formula = format!("={}", usual_day_cells.join("+"));
-        // Add salary
-        let salary = match salary {
-            0 => "".to_string(),
-            _ => salary.to_string(),
-        };
+// Compute timesheet of settings period with holidays, look settings are not used
+pub async fn get_timesheet(salary: u32, settings: &Settings) -> EResult<Timesheet> {
+    settings.pay_rules.validate()?;
+    settings.schedule.validate()?;
+    let mut days = fetch_days(&settings.calendar, settings.period).await?;
+    days.apply_employment(&settings.employment);
+    if days.is_empty() {
+        return Err(Error::InvalidOptions(
+            "Employment dates are out of period!".to_string(),
+        ));
+    }
+    Ok(Timesheet::new(
+        &days,
+        salary,
+        settings.pay_rules,
+        settings.schedule,
+    ))
+}
 
-        let mut format = cell_style(DataType::Money, CellType::InputHeader);
-        month_worksheet.write_formula_with_format(
-            4,
-            column_name_to_number("E"),
-            format!("={}", salary).as_str(),
-            &format,
-        )?;
-        // Add a total block
-        add_total_cells(
-            month_worksheet,
-            work_hours,
-            month_days.len() as u8,
-            usual_days_formula,
-            weekends_formula,
-        )?;
-        // Polish worksheet
-        // Do wider border at bottom of days block
-        format = Format::new().set_border_top(FormatBorder::Medium);
-        month_worksheet.merge_range(
-            3 + month_days.len() as u32,
-            column_name_to_number("A"),
-            3 + month_days.len() as u32,
-            column_name_to_number("C"),
-            "",
-            &format,
-        )?;
-        // Autofit columns
-        month_worksheet.autofit();
-        // Make E column wider
-        month_worksheet.set_column_width(column_name_to_number("E"), 12)?;
-        // Make C column wider
-        month_worksheet.set_column_width(column_name_to_number("C"), 10)?;
-        // Make B column narrower
-        month_worksheet.set_column_width(column_name_to_number("B"), 7.5)?;
-        // Set month name
-        month_worksheet.set_name(month_days.first().unwrap().month_name())?;
+// Generate workbook with a row per employee at month sheets, calendar is fetched once
+pub async fn get_team_table(employees: &[Employee], settings: &Settings) -> EResult<Vec<u8>> {
+    if employees.is_empty() {
+        return Err(Error::InvalidOptions("Team has no employees!".to_string()));
+    }
+    settings.pay_rules.validate()?;
+    for employee in employees {
+        employee.schedule.validate()?;
     }
-    // Convert struct to bytes and return it
-    let buf = table.save_to_buffer()?;
-    Ok(buf)
+    let days = fetch_days(&settings.calendar, settings.period).await?;
+    team::render_team(&days, employees, settings)
+}
+
+// Holidays of year from calendar in date order, errors if calendar has no such year
+pub async fn get_holidays(year: i32, calendar: &CalendarSource) -> EResult<Vec<NaiveDate>> {
+    let holidays = FetchedDates::init(calendar).await?;
+    if !holidays.has_year(year) {
+        return Err(Error::NoDataForYear(year));
+    }
+    let mut dates: Vec<NaiveDate> = holidays
+        .get_holidays()
+        .into_iter()
+        .filter(|date| date.year() == year)
+        .collect();
+    dates.sort();
+    Ok(dates)
+}
+
+// Days of period, last year of holiday calendar by default
+async fn fetch_days(calendar: &CalendarSource, period: Option<Period>) -> EResult<Days> {
+    // Fetch holidays
+    let holidays = FetchedDates::init(calendar).await?;
+    let period = match period {
+        Some(period) => period,
+        None => Period::year(holidays.last_year()?)?,
+    };
+    // Days of missing year would be generated without holidays
+    for year in period.start().year()..=period.end().year() {
+        if !holidays.has_year(year) {
+            return Err(Error::NoDataForYear(year));
+        }
+    }
+    // Generate days for filling
+    Ok(Days::new(&period, &holidays.get_holidays()))
+}
+
+// Read hours and salaries back from table made by get_filled_table, totals are computed in Rust
+pub fn read_filled_table(table: &[u8]) -> EResult<Timesheet> {
+    reader::read_timesheet(table)
 }
 
+pub mod builder;
 mod data;
+pub mod error;
+mod json;
+mod layout;
 mod network;
+mod ods;
+pub mod options;
+#[cfg(feature = "pdf")]
+mod pdf;
+mod reader;
+pub mod render;
+pub mod settings;
 mod styles;
+mod t13;
+pub mod team;
+pub mod theme;
+pub mod timesheet;
+mod xlsx;
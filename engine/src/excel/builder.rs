@@ -0,0 +1,111 @@
+use crate::excel::{
+    error::Result as EResult,
+    get_filled_table_with_settings, get_timesheet,
+    settings::{
+        CalendarSource, Employment, FormFields, Locale, OutputFormat, PayRules, Period, PrintSetup,
+        Protection, Schedule, Settings,
+    },
+    theme::Theme,
+    timesheet::Timesheet,
+};
+
+/// Timesheet generation with options, unset ones are the same as at `get_filled_table`
+#[derive(Debug, Clone, Default)]
+pub struct TimesheetBuilder {
+    salary: u32,
+    year: Option<i32>,
+    settings: Settings,
+}
+
+impl TimesheetBuilder {
+    /// Monthly salary, salary cells stay empty if zero
+    pub fn new(salary: u32) -> Self {
+        Self {
+            salary,
+            ..Default::default()
+        }
+    }
+
+    /// Whole year, last year of holiday calendar is generated if neither year nor period is set
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = Some(year);
+        self.settings.period = None;
+        self
+    }
+
+    pub fn period(mut self, period: Period) -> Self {
+        self.settings.period = Some(period);
+        self.year = None;
+        self
+    }
+
+    pub fn calendar(mut self, calendar: CalendarSource) -> Self {
+        self.settings.calendar = calendar;
+        self
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.settings.locale = locale;
+        self
+    }
+
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.settings.theme = theme;
+        self
+    }
+
+    pub fn pay_rules(mut self, pay_rules: PayRules) -> Self {
+        self.settings.pay_rules = pay_rules;
+        self
+    }
+
+    pub fn schedule(mut self, schedule: Schedule) -> Self {
+        self.settings.schedule = schedule;
+        self
+    }
+
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        self.settings.format = format;
+        self
+    }
+
+    /// Worksheets stay unprotected if none
+    pub fn protection(mut self, protection: Option<Protection>) -> Self {
+        self.settings.protection = protection;
+        self
+    }
+
+    pub fn print(mut self, print: PrintSetup) -> Self {
+        self.settings.print = print;
+        self
+    }
+
+    pub fn form(mut self, form: FormFields) -> Self {
+        self.settings.form = form;
+        self
+    }
+
+    pub fn employment(mut self, employment: Employment) -> Self {
+        self.settings.employment = employment;
+        self
+    }
+
+    /// Settings with year turned into period, they are checked at generation
+    pub fn settings(&self) -> EResult<Settings> {
+        let mut settings = self.settings.clone();
+        if let Some(year) = self.year {
+            settings.period = Some(Period::year(year)?);
+        }
+        Ok(settings)
+    }
+
+    /// Computed timesheet without rendering
+    pub async fn timesheet(&self) -> EResult<Timesheet> {
+        get_timesheet(self.salary, &self.settings()?).await
+    }
+
+    /// File in chosen output format
+    pub async fn build(&self) -> EResult<Vec<u8>> {
+        get_filled_table_with_settings(self.salary, &self.settings()?).await
+    }
+}
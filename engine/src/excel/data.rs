@@ -1,3 +1,6 @@
 pub(super) mod days;
 pub(super) mod headers;
+pub(super) mod legend;
+pub(super) mod names;
+pub(super) mod summary;
 pub(super) mod total;
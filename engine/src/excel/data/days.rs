@@ -1,21 +1,110 @@
 use std::collections::HashSet;
 
-use crate::excel::styles::{CellType, DataType, cell_style};
-use anyhow::Result as AResult;
-use chrono::{Datelike, Local, NaiveDate, Weekday};
+use crate::excel::data::names;
+use crate::excel::error::Result as EResult;
+use crate::excel::layout::{DayColumn, MONTH_LAYOUT};
+use crate::excel::settings::{Employment, Locale, PayRules, Period, Schedule};
+use crate::excel::styles::{CellType, DataType, cell_style, conditional_style};
+use crate::excel::theme::Theme;
+use chrono::{Datelike, Months, NaiveDate, Weekday};
 use derive_more::{Deref, DerefMut, IntoIterator};
 use rust_xlsxwriter::{
-    FormatBorder, Formula, utility::column_name_to_number, worksheet::Worksheet,
+    ConditionalFormatFormula, DataValidation, FormatBorder, Formula, utility::cell_range,
+    worksheet::Worksheet,
 };
 
-#[derive(Default, Debug, Clone, Copy)]
-pub(crate) enum DayType {
+/// Type of day, picks planned hours and pay multiplier
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum DayType {
+    /// Working day
     #[default]
     Usual,
-    Earn,
-    Weekend,
+    /// Day off of calendar, saturday and sunday without calendar
+    RestDay,
+    /// Public holiday
+    Holiday,
+    Vacation,
+    SickLeave,
 }
 
+impl DayType {
+    pub(crate) const ALL: [DayType; 5] = [
+        DayType::Usual,
+        DayType::RestDay,
+        DayType::Holiday,
+        DayType::Vacation,
+        DayType::SickLeave,
+    ];
+
+    /// Name used in day type dropdown and formulas
+    pub fn name(&self) -> &'static str {
+        match self {
+            DayType::Usual => "Рабочий",
+            DayType::RestDay => "Выходной",
+            DayType::Holiday => "Праздник",
+            DayType::Vacation => "Отпуск",
+            DayType::SickLeave => "Больничный",
+        }
+    }
+
+    // Day type by name from day type column
+    pub(crate) fn from_name(name: &str) -> Option<DayType> {
+        DayType::ALL
+            .into_iter()
+            .find(|day_type| day_type.name() == name)
+    }
+
+    pub(crate) fn cell_type(&self) -> CellType {
+        match self {
+            DayType::Usual => CellType::Usual,
+            DayType::RestDay => CellType::RestDay,
+            DayType::Holiday => CellType::Holiday,
+            DayType::Vacation => CellType::Vacation,
+            DayType::SickLeave => CellType::SickLeave,
+        }
+    }
+
+    // Planned hours of day, only usual working day has them
+    pub(crate) fn planned_hours(&self, schedule: &Schedule) -> f64 {
+        match self {
+            DayType::Usual => schedule.work_day_hours,
+            _ => 0.0,
+        }
+    }
+
+    // Hours at vacation and sick leave are not paid
+    pub(crate) fn multiplier(&self, pay_rules: &PayRules) -> f64 {
+        match self {
+            DayType::Usual => pay_rules.overtime,
+            DayType::RestDay => pay_rules.rest_day,
+            DayType::Holiday => pay_rules.holiday,
+            DayType::Vacation | DayType::SickLeave => 0.0,
+        }
+    }
+}
+
+// Planned hours of usual working day by default
+pub(crate) const WORK_DAY_HOURS: u8 = 8;
+
+// Month and day of non-working public holidays by labour code, days off moved from them
+// are rest days
+const PUBLIC_HOLIDAYS: [(u32, u32); 14] = [
+    (1, 1),
+    (1, 2),
+    (1, 3),
+    (1, 4),
+    (1, 5),
+    (1, 6),
+    (1, 7),
+    (1, 8),
+    (2, 23),
+    (3, 8),
+    (5, 1),
+    (5, 9),
+    (6, 12),
+    (11, 4),
+];
+
 pub(crate) enum Season {
     Winter,
     Spring,
@@ -23,15 +112,72 @@ pub(crate) enum Season {
     Autumn,
 }
 
-#[derive(Default, Debug)]
-pub(crate) struct Day {
+// Hours cell of days out of employment or period, formulas skip text
+pub(crate) const NOT_EMPLOYED_MARK: &str = "—";
+pub(crate) const NOT_EMPLOYED: &str = "Вне табеля";
+
+/// Calendar day with its type
+#[derive(Debug, Clone)]
+pub struct Day {
     day: NaiveDate,
     flag: DayType,
+    employed: bool,
+    // Planned hours corrected by user, day type hours if none
+    planned: Option<f64>,
 }
 
 impl Day {
     pub(crate) fn new(day: NaiveDate, flag: DayType) -> Self {
-        Self { day, flag }
+        Self {
+            day,
+            flag,
+            employed: true,
+            planned: None,
+        }
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        self.day
+    }
+
+    pub fn day_type(&self) -> DayType {
+        self.flag
+    }
+
+    /// Day is between hire and termination dates
+    pub fn employed(&self) -> bool {
+        self.employed
+    }
+
+    pub(crate) fn set_employed(&mut self, employed: bool) {
+        self.employed = employed;
+    }
+
+    pub(crate) fn corrected_planned_hours(&self) -> Option<f64> {
+        self.planned
+    }
+
+    pub(crate) fn set_planned_hours(&mut self, hours: Option<f64>) {
+        self.planned = hours;
+    }
+
+    // Days out of employment are not planned
+    pub(crate) fn planned_hours(&self, schedule: &Schedule) -> f64 {
+        match self.employed {
+            true => self
+                .planned
+                .unwrap_or_else(|| self.flag.planned_hours(schedule)),
+            false => 0.0,
+        }
+    }
+
+    // Day type colour or grey out of employment, only PDF colours days by day itself
+    #[cfg(feature = "pdf")]
+    pub(crate) fn cell_type(&self) -> CellType {
+        match self.employed {
+            true => self.flag.cell_type(),
+            false => CellType::NotEmployed,
+        }
     }
 
     pub(crate) fn year(&self) -> i32 {
@@ -42,42 +188,67 @@ impl Day {
         self.day.day()
     }
 
-    pub(crate) fn weekday_short(&self) -> String {
-        match self.day.weekday() {
-            Weekday::Mon => "Пн".to_string(),
-            Weekday::Tue => "Вт".to_string(),
-            Weekday::Wed => "Ср".to_string(),
-            Weekday::Thu => "Чт".to_string(),
-            Weekday::Fri => "Пт".to_string(),
-            Weekday::Sat => "Сб".to_string(),
-            Weekday::Sun => "Вс".to_string(),
+    pub(crate) fn weekday_short(&self, locale: Locale) -> String {
+        match locale {
+            Locale::Ru => match self.day.weekday() {
+                Weekday::Mon => "Пн".to_string(),
+                Weekday::Tue => "Вт".to_string(),
+                Weekday::Wed => "Ср".to_string(),
+                Weekday::Thu => "Чт".to_string(),
+                Weekday::Fri => "Пт".to_string(),
+                Weekday::Sat => "Сб".to_string(),
+                Weekday::Sun => "Вс".to_string(),
+            },
         }
     }
 
-    pub(crate) fn month_name(&self) -> String {
-        match self.day.month() {
-            1 => "❄️ Январь".to_string(),
-            2 => "🌨️ Февраль".to_string(),
-            3 => "🌱 Март".to_string(),
-            4 => "🌸 Апрель".to_string(),
-            5 => "🌿 Май".to_string(),
-            6 => "☀️ Июнь".to_string(),
-            7 => "🏖️ Июль".to_string(),
-            8 => "🍉 Август".to_string(),
-            9 => "🍂 Сентябрь".to_string(),
-            10 => "🍁 Октябрь".to_string(),
-            11 => "🌧️ Ноябрь".to_string(),
-            12 => "🎄 Декабрь".to_string(),
-            _ => "❓ Неизвестный месяц".to_string(),
+    // Month name with emoji, used as sheet name
+    pub(crate) fn month_name(&self, locale: Locale) -> String {
+        let emoji = match self.day.month() {
+            1 => "❄️",
+            2 => "🌨️",
+            3 => "🌱",
+            4 => "🌸",
+            5 => "🌿",
+            6 => "☀️",
+            7 => "🏖️",
+            8 => "🍉",
+            9 => "🍂",
+            10 => "🍁",
+            11 => "🌧️",
+            12 => "🎄",
+            _ => "❓",
+        };
+        format!("{} {}", emoji, self.month_title(locale))
+    }
+
+    // Plain month name for fonts without emoji
+    pub(crate) fn month_title(&self, locale: Locale) -> &'static str {
+        match locale {
+            Locale::Ru => match self.day.month() {
+                1 => "Январь",
+                2 => "Февраль",
+                3 => "Март",
+                4 => "Апрель",
+                5 => "Май",
+                6 => "Июнь",
+                7 => "Июль",
+                8 => "Август",
+                9 => "Сентябрь",
+                10 => "Октябрь",
+                11 => "Ноябрь",
+                12 => "Декабрь",
+                _ => "Неизвестный месяц",
+            },
         }
     }
 
     pub(crate) fn season(&self) -> Season {
         match self.day.month() {
             1 | 2 | 12 => Season::Winter,
-            3 | 4 | 5 => Season::Spring,
-            6 | 7 | 8 => Season::Summer,
-            9 | 10 | 11 => Season::Autumn,
+            3..=5 => Season::Spring,
+            6..=8 => Season::Summer,
+            9..=11 => Season::Autumn,
             _ => Season::Winter,
         }
     }
@@ -93,26 +264,46 @@ impl FromIterator<Day> for Days {
 }
 
 impl Days {
-    pub(crate) fn new_with_holidays(holidays: HashSet<NaiveDate>) -> Self {
-        let current_year = match holidays.iter().next().cloned() {
-            Some(v) => v.year(),
-            None => Local::now().date_naive().year(),
-        };
-        let first_date = NaiveDate::from_ymd_opt(current_year as i32, 1, 1).unwrap();
-        let days: Days = first_date
+    // Whole months of period, weekends and holidays are marked. Days out of period are
+    // marked like out of employment, so norm and rate stay of whole month.
+    // Calendar lists every day off, so weekend missing in it is a transferred working day.
+    // Days off at public holiday dates are holidays, the rest are rest days.
+    // Without calendar days off are saturday and sunday
+    pub(crate) fn new(period: &Period, holidays: &HashSet<NaiveDate>) -> Self {
+        let first_date = period.start().with_day(1).unwrap();
+        let last_date = period.end().with_day(1).unwrap() + Months::new(1) - chrono::Days::new(1);
+        first_date
             .iter_days()
-            .take_while(|d| d.year() == current_year as i32)
+            .take_while(|d| *d <= last_date)
             .map(|d| {
-                if d.weekday() == Weekday::Sun {
-                    Day::new(d, DayType::Weekend)
-                } else if d.weekday() == Weekday::Sat || holidays.contains(&d) {
-                    Day::new(d, DayType::Earn)
-                } else {
+                let day_off = match holidays.is_empty() {
+                    true => d.weekday() == Weekday::Sat || d.weekday() == Weekday::Sun,
+                    false => holidays.contains(&d),
+                };
+                let mut day = if !day_off {
                     Day::new(d, DayType::Usual)
-                }
+                } else if PUBLIC_HOLIDAYS.contains(&(d.month(), d.day())) {
+                    Day::new(d, DayType::Holiday)
+                } else {
+                    Day::new(d, DayType::RestDay)
+                };
+                day.set_employed(period.start() <= d && d <= period.end());
+                day
             })
+            .collect()
+    }
+
+    // Mark days out of employment, months without employed days are dropped
+    pub(crate) fn apply_employment(&mut self, employment: &Employment) {
+        for day in self.iter_mut() {
+            day.set_employed(day.employed && employment.contains(day.day));
+        }
+        let employed_months: HashSet<(i32, u32)> = self
+            .iter()
+            .filter(|day| day.employed)
+            .map(|day| (day.year(), day.day.month()))
             .collect();
-        days
+        self.retain(|day| employed_months.contains(&(day.year(), day.day.month())));
     }
 
     pub(crate) fn split_months(&self) -> impl Iterator<Item = &[Day]> {
@@ -120,28 +311,205 @@ impl Days {
     }
 }
 
-pub(crate) fn add_day_cell(month_worksheet: &mut Worksheet, day: &Day) -> AResult<DayType> {
-    let day_row = 2 + day.number();
+// Worked hours come from timesheet, generated one has zeros
+pub(crate) fn add_day_cell(
+    month_worksheet: &mut Worksheet,
+    (day, worked_hours): (&Day, &f64),
+    day_row: u32,
+    theme: &Theme,
+    locale: Locale,
+) -> EResult<()> {
+    // Days out of employment are locked and greyed out
+    if !day.employed {
+        return add_not_employed_cell(month_worksheet, day, day_row, theme, locale);
+    }
+    let days = &MONTH_LAYOUT.days;
+    let day_type = days.address(DayColumn::DayType, day_row);
 
-    let mut format = match day.flag {
-        DayType::Earn => cell_style(DataType::UsualText, CellType::Earn),
-        DayType::Weekend => cell_style(DataType::UsualText, CellType::Weekend),
-        DayType::Usual => cell_style(DataType::UsualText, CellType::Usual),
+    let mut format = cell_style(theme, DataType::UsualText, day.flag.cell_type());
+    // Hours, planned hours and day type are input cells
+    let input_format = format.clone().set_unlocked();
+    let mut cell = days.cell(DayColumn::Hours, day_row);
+    month_worksheet.write_with_format(cell.row, cell.col, *worked_hours, &input_format)?;
+    // Planned hours depends on day type, can be corrected by user
+    cell = days.cell(DayColumn::PlannedHours, day_row);
+    match day.planned {
+        Some(hours) => {
+            month_worksheet.write_with_format(cell.row, cell.col, hours, &input_format)?
+        }
+        None => month_worksheet.write_formula_with_format(
+            cell.row,
+            cell.col,
+            Formula::new(format!(
+                "=IF({}=\"{}\",{},0)",
+                day_type,
+                DayType::Usual.name(),
+                names::WORK_DAY_HOURS
+            )),
+            &input_format,
+        )?,
     };
-    month_worksheet.write_with_format(day_row, column_name_to_number("B"), "0", &format)?;
+    // Day type, can be changed by user
+    cell = days.cell(DayColumn::DayType, day_row);
+    month_worksheet.write_with_format(cell.row, cell.col, day.flag.name(), &input_format)?;
+    cell = days.cell(DayColumn::Day, day_row);
     month_worksheet.write_with_format(
-        day_row,
-        0,
-        format!("{} {}", day.number(), day.weekday_short()),
+        cell.row,
+        cell.col,
+        format!("{} {}", day.number(), day.weekday_short(locale)),
         &format.set_border_left(FormatBorder::Medium),
     )?;
 
-    format = cell_style(DataType::Money, CellType::TotalBonus);
+    // Multiplier is picked by day type, nested IF ends with zero for unknown type
+    let multiplier = DayType::ALL
+        .iter()
+        .rev()
+        .fold("0".to_string(), |otherwise, flag| {
+            format!(
+                "IF({}=\"{}\",{},{})",
+                day_type,
+                flag.name(),
+                names::rate_name(flag),
+                otherwise
+            )
+        });
+    format = cell_style(theme, DataType::Money, CellType::TotalBonus);
+    cell = days.cell(DayColumn::Bonus, day_row);
     month_worksheet.write_formula_with_format(
-        day_row,
-        column_name_to_number("C"),
-        Formula::new(format!("=E5/E1*B{}*2", day_row + 1)),
+        cell.row,
+        cell.col,
+        Formula::new(format!(
+            "={}*{}*{}",
+            names::HOUR_RATE,
+            days.address(DayColumn::Hours, day_row),
+            multiplier
+        )),
         &format,
-    )?; //Complite it
-    Ok(day.flag)
+    )?;
+    Ok(())
+}
+
+// Hours are marked as not worked, day type stays for month norm
+fn add_not_employed_cell(
+    month_worksheet: &mut Worksheet,
+    day: &Day,
+    day_row: u32,
+    theme: &Theme,
+    locale: Locale,
+) -> EResult<()> {
+    let days = &MONTH_LAYOUT.days;
+    let format = cell_style(theme, DataType::UsualText, CellType::NotEmployed);
+    let mut cell = days.cell(DayColumn::Hours, day_row);
+    month_worksheet.write_with_format(cell.row, cell.col, NOT_EMPLOYED_MARK, &format)?;
+    cell = days.cell(DayColumn::PlannedHours, day_row);
+    month_worksheet.write_with_format(cell.row, cell.col, 0, &format)?;
+    cell = days.cell(DayColumn::DayType, day_row);
+    month_worksheet.write_with_format(cell.row, cell.col, day.flag.name(), &format)?;
+    cell = days.cell(DayColumn::Day, day_row);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        format!("{} {}", day.number(), day.weekday_short(locale)),
+        &format.set_border_left(FormatBorder::Medium),
+    )?;
+    cell = days.cell(DayColumn::Bonus, day_row);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        0,
+        &cell_style(theme, DataType::Money, CellType::TotalBonus),
+    )?;
+    Ok(())
+}
+
+// Rules cover rows of employed days only, rows start from 1
+pub(crate) fn add_day_type_rules(
+    month_worksheet: &mut Worksheet,
+    (first_day_row, last_day_row): (u32, u32),
+    theme: &Theme,
+) -> EResult<()> {
+    let days = &MONTH_LAYOUT.days;
+    let first_row = days.cell(DayColumn::Day, first_day_row).row;
+    let last_row = days.cell(DayColumn::Day, last_day_row).row;
+    let type_col = days.col(DayColumn::DayType);
+    // Dropdown with day types
+    let names = DayType::ALL.map(|day_type| day_type.name());
+    let validation = DataValidation::new()
+        .allow_list_strings(&names)?
+        .set_error_title("Тип дня")?
+        .set_error_message(format!("Выберите тип дня: {}", names.join(", ")))?;
+    month_worksheet.add_data_validation(first_row, type_col, last_row, type_col, &validation)?;
+    // Day row colour follows day type, bonus column keeps own style
+    let colored_range = DayColumn::ALL
+        .into_iter()
+        .filter(|column| *column != DayColumn::Bonus)
+        .map(|column| cell_range(first_row, days.col(column), last_row, days.col(column)))
+        .collect::<Vec<String>>()
+        .join(" ");
+    for day_type in DayType::ALL {
+        let rule = ConditionalFormatFormula::new()
+            .set_rule(Formula::new(format!(
+                "={}=\"{}\"",
+                days.row_address(DayColumn::DayType, first_day_row),
+                day_type.name()
+            )))
+            .set_multi_range(&colored_range)
+            .set_format(conditional_style(theme, day_type.cell_type()));
+        month_worksheet.add_conditional_format(
+            first_row,
+            days.first_col(),
+            last_row,
+            days.last_col(),
+            &rule,
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    // Weekends of month with given changes, like calendar lists them
+    fn calendar(year: i32, month: u32, added: &[u32], removed: &[u32]) -> HashSet<NaiveDate> {
+        let mut holidays: HashSet<NaiveDate> = date(year, month, 1)
+            .iter_days()
+            .take_while(|d| d.month() == month)
+            .filter(|d| d.weekday() == Weekday::Sat || d.weekday() == Weekday::Sun)
+            .collect();
+        holidays.extend(added.iter().map(|day| date(year, month, *day)));
+        holidays.retain(|d| !removed.contains(&d.day()));
+        holidays
+    }
+
+    #[test]
+    fn day_types_come_from_calendar() {
+        // Saturday 1st is transferred working day, its day off is at Monday 3rd
+        let holidays = calendar(2025, 11, &[3, 4], &[1]);
+        let days = Days::new(&Period::month(2025, 11).unwrap(), &holidays);
+        assert_eq!(days[0].day_type(), DayType::Usual);
+        assert_eq!(days[1].day_type(), DayType::RestDay);
+        assert_eq!(days[2].day_type(), DayType::RestDay);
+        assert_eq!(days[3].day_type(), DayType::Holiday);
+        let usual = days.iter().filter(|day| day.day_type() == DayType::Usual);
+        assert_eq!(usual.count(), 19);
+
+        // Sunday 8th is public holiday, its day off is moved to Monday 9th
+        let holidays = calendar(2026, 3, &[9], &[]);
+        let days = Days::new(&Period::month(2026, 3).unwrap(), &holidays);
+        assert_eq!(days[7].day_type(), DayType::Holiday);
+        assert_eq!(days[8].day_type(), DayType::RestDay);
+    }
+
+    #[test]
+    fn weekends_are_rest_days_without_calendar() {
+        let days = Days::new(&Period::month(2026, 3).unwrap(), &HashSet::new());
+        assert_eq!(days[13].day_type(), DayType::RestDay);
+        assert_eq!(days[14].day_type(), DayType::RestDay);
+        assert_eq!(days[15].day_type(), DayType::Usual);
+    }
 }
@@ -1,64 +1,167 @@
 use crate::excel::{
     data::days::{Day, Season},
+    error::Result as EResult,
+    layout::{DayColumn, MONTH_LAYOUT, TotalRow},
+    settings::Locale,
     styles::{CellType, DataType, cell_style},
+    theme::Theme,
 };
-use anyhow::Result as AResult;
-use rust_xlsxwriter::{utility::column_name_to_number, worksheet::Worksheet};
+use rust_xlsxwriter::worksheet::Worksheet;
 
-pub(crate) fn add_header_cells(month_worksheet: &mut Worksheet, first_day: &Day) -> AResult<()> {
+pub(crate) const APP_INFO: &str = "dev.release";
+
+// Day block column titles
+pub(crate) fn day_label(column: DayColumn, locale: Locale) -> &'static str {
+    match locale {
+        Locale::Ru => match column {
+            DayColumn::Day => "Число/День",
+            DayColumn::Hours => "Часы",
+            DayColumn::Bonus => "Доплата",
+            DayColumn::PlannedHours => "Норма",
+            DayColumn::DayType => "Тип дня",
+        },
+    }
+}
+
+// Total block row titles
+pub(crate) fn total_label(row: TotalRow, locale: Locale) -> &'static str {
+    match locale {
+        Locale::Ru => match row {
+            TotalRow::NormHours => "Рабочие часы:",
+            TotalRow::OvertimeHours => "Часы переработки:",
+            TotalRow::WeekendHours => "Часы выходных:",
+            TotalRow::HourRate => "Ставка в час:",
+            TotalRow::Salary => "Оклад:",
+            TotalRow::Payout => "К получению:",
+        },
+    }
+}
+
+pub(crate) fn add_header_cells(
+    month_worksheet: &mut Worksheet,
+    first_day: &Day,
+    theme: &Theme,
+    locale: Locale,
+) -> EResult<()> {
+    let header = &MONTH_LAYOUT.header;
+    let days = &MONTH_LAYOUT.days;
+    let total = &MONTH_LAYOUT.total;
     // Year
-    let mut format = cell_style(DataType::UsualText, CellType::Header);
-    month_worksheet.write_with_format(0, column_name_to_number("A"), first_day.year(), &format)?;
+    let mut format = cell_style(theme, DataType::UsualText, CellType::Header);
+    let year = header.year();
+    month_worksheet.write_with_format(year.row, year.col, first_day.year(), &format)?;
     // AppInfo
-    month_worksheet.merge_range(
-        0,
-        column_name_to_number("B"),
-        0,
-        column_name_to_number("C"),
-        "dev.release",
-        &format,
-    )?;
+    let (first, last) = header.app_info();
+    month_worksheet.merge_range(first.row, first.col, last.row, last.col, APP_INFO, &format)?;
 
     // Day header
-    month_worksheet.write_with_format(2, column_name_to_number("A"), "Число/День", &format)?;
+    let mut cell = days.header(DayColumn::Day);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        day_label(DayColumn::Day, locale),
+        &format,
+    )?;
     // Bonus header
-    month_worksheet.write_with_format(2, column_name_to_number("C"), "Доплата", &format)?;
+    cell = days.header(DayColumn::Bonus);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        day_label(DayColumn::Bonus, locale),
+        &format,
+    )?;
     // Total month work hours header
-    month_worksheet.write_with_format(0, column_name_to_number("D"), "Рабочие часы:", &format)?;
-    // Total weekends hours header
-    month_worksheet.write_with_format(1, column_name_to_number("D"), "Часы выходных:", &format)?;
+    cell = total.label(TotalRow::NormHours);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        total_label(TotalRow::NormHours, locale),
+        &format,
+    )?;
     // Total overvork hours header
+    cell = total.label(TotalRow::OvertimeHours);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        total_label(TotalRow::OvertimeHours, locale),
+        &format,
+    )?;
+    // Total weekends hours header
+    cell = total.label(TotalRow::WeekendHours);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        total_label(TotalRow::WeekendHours, locale),
+        &format,
+    )?;
+    // Hour rate header
+    cell = total.label(TotalRow::HourRate);
     month_worksheet.write_with_format(
-        2,
-        column_name_to_number("D"),
-        "Часы переработки:",
+        cell.row,
+        cell.col,
+        total_label(TotalRow::HourRate, locale),
         &format,
     )?;
 
-    format = cell_style(DataType::UsualText, CellType::InputHeader);
+    format = cell_style(theme, DataType::UsualText, CellType::InputHeader);
     // Hours header
-    month_worksheet.write_with_format(2, column_name_to_number("B"), "Часы", &format)?;
+    cell = days.header(DayColumn::Hours);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        day_label(DayColumn::Hours, locale),
+        &format,
+    )?;
+    // Planned hours header
+    cell = days.header(DayColumn::PlannedHours);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        day_label(DayColumn::PlannedHours, locale),
+        &format,
+    )?;
+    // Day type header
+    cell = days.header(DayColumn::DayType);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        day_label(DayColumn::DayType, locale),
+        &format,
+    )?;
     // Salary input header
-    month_worksheet.write_with_format(4, column_name_to_number("D"), "Оклад:", &format)?;
+    cell = total.label(TotalRow::Salary);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        total_label(TotalRow::Salary, locale),
+        &format,
+    )?;
 
-    format = cell_style(DataType::UsualText, CellType::TotalPayment);
+    format = cell_style(theme, DataType::UsualText, CellType::TotalPayment);
     // Total payout header
-    month_worksheet.write_with_format(5, column_name_to_number("D"), "К получению:", &format)?;
+    cell = total.label(TotalRow::Payout);
+    month_worksheet.write_with_format(
+        cell.row,
+        cell.col,
+        total_label(TotalRow::Payout, locale),
+        &format,
+    )?;
 
     // Month
     format = match first_day.season() {
-        Season::Winter => cell_style(DataType::UsualText, CellType::MonthWinter),
-        Season::Spring => cell_style(DataType::UsualText, CellType::MonthSpring),
-        Season::Summer => cell_style(DataType::UsualText, CellType::MonthSummer),
-        Season::Autumn => cell_style(DataType::UsualText, CellType::MonthAutumn),
+        Season::Winter => cell_style(theme, DataType::UsualText, CellType::MonthWinter),
+        Season::Spring => cell_style(theme, DataType::UsualText, CellType::MonthSpring),
+        Season::Summer => cell_style(theme, DataType::UsualText, CellType::MonthSummer),
+        Season::Autumn => cell_style(theme, DataType::UsualText, CellType::MonthAutumn),
     };
 
+    let (first, last) = header.month();
     month_worksheet.merge_range(
-        1,
-        column_name_to_number("A"),
-        1,
-        column_name_to_number("C"),
-        first_day.month_name().as_str(),
+        first.row,
+        first.col,
+        last.row,
+        last.col,
+        first_day.month_name(locale).as_str(),
         &format,
     )?;
     Ok(())
@@ -0,0 +1,41 @@
+use rust_xlsxwriter::worksheet::Worksheet;
+
+use crate::excel::{
+    data::days::{DayType, NOT_EMPLOYED},
+    error::Result as EResult,
+    layout::MONTH_LAYOUT,
+    styles::{CellType, DataType, cell_style},
+    theme::Theme,
+};
+
+pub(crate) const LEGEND_TITLE: &str = "Обозначения";
+pub(crate) const INPUT_LEGEND: &str = "Ввод данных";
+
+pub(crate) fn add_legend_cells(
+    month_worksheet: &mut Worksheet,
+    prorated: bool,
+    theme: &Theme,
+) -> EResult<()> {
+    let legend = &MONTH_LAYOUT.legend;
+    // Title
+    let mut format = cell_style(theme, DataType::UsualText, CellType::Header);
+    let mut cell = legend.title();
+    month_worksheet.write_with_format(cell.row, cell.col, LEGEND_TITLE, &format)?;
+    // Day types with their colours
+    for (index, day_type) in DayType::ALL.iter().enumerate() {
+        format = cell_style(theme, DataType::UsualText, day_type.cell_type());
+        cell = legend.entry(index as u32);
+        month_worksheet.write_with_format(cell.row, cell.col, day_type.name(), &format)?;
+    }
+    // Input cells colour, the cell itself stays locked
+    format = cell_style(theme, DataType::UsualText, CellType::InputHeader);
+    cell = legend.entry(DayType::ALL.len() as u32);
+    month_worksheet.write_with_format(cell.row, cell.col, INPUT_LEGEND, &format)?;
+    // Days out of employment are shown only at months having them
+    if prorated {
+        format = cell_style(theme, DataType::UsualText, CellType::NotEmployed);
+        cell = legend.entry(DayType::ALL.len() as u32 + 1);
+        month_worksheet.write_with_format(cell.row, cell.col, NOT_EMPLOYED, &format)?;
+    }
+    Ok(())
+}
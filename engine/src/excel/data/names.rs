@@ -0,0 +1,88 @@
+use rust_xlsxwriter::workbook::Workbook;
+
+use crate::excel::{
+    data::days::DayType,
+    error::Result as EResult,
+    layout::{DayColumn, MONTH_LAYOUT, TotalRow},
+    settings::{PayRules, Schedule},
+};
+
+// Worksheet names of total block cells
+pub(crate) const NORM_HOURS: &str = "NormHours";
+pub(crate) const OVERTIME_HOURS: &str = "OvertimeHours";
+pub(crate) const WEEKEND_HOURS: &str = "WeekendHours";
+pub(crate) const HOUR_RATE: &str = "HourRate";
+pub(crate) const SALARY: &str = "Salary";
+pub(crate) const PAYOUT: &str = "Payout";
+
+// Worksheet names of day block columns
+pub(crate) const HOURS: &str = "Hours";
+pub(crate) const BONUS: &str = "Bonus";
+pub(crate) const PLANNED_HOURS: &str = "PlannedHours";
+pub(crate) const DAY_TYPES: &str = "DayTypes";
+
+// Workbook names
+pub(crate) const WORK_DAY_HOURS: &str = "WorkDayHours";
+
+// Workbook name of pay multiplier for day type
+pub(crate) fn rate_name(day_type: &DayType) -> &'static str {
+    match day_type {
+        DayType::Usual => "OvertimeRate",
+        DayType::RestDay => "RestDayRate",
+        DayType::Holiday => "HolidayRate",
+        DayType::Vacation => "VacationRate",
+        DayType::SickLeave => "SickLeaveRate",
+    }
+}
+
+pub(crate) fn define_rule_names(
+    table: &mut Workbook,
+    pay_rules: &PayRules,
+    schedule: &Schedule,
+) -> EResult<()> {
+    table.define_name(WORK_DAY_HOURS, &format!("={}", schedule.work_day_hours))?;
+    for day_type in DayType::ALL {
+        table.define_name(
+            rate_name(&day_type),
+            &format!("={}", day_type.multiplier(pay_rules)),
+        )?;
+    }
+    Ok(())
+}
+
+pub(crate) fn define_month_names(
+    table: &mut Workbook,
+    sheet_name: &str,
+    total_days: u8,
+) -> EResult<()> {
+    let days = &MONTH_LAYOUT.days;
+    let total = &MONTH_LAYOUT.total;
+    let cells = [
+        (NORM_HOURS, total.value(TotalRow::NormHours).absolute()),
+        (
+            OVERTIME_HOURS,
+            total.value(TotalRow::OvertimeHours).absolute(),
+        ),
+        (
+            WEEKEND_HOURS,
+            total.value(TotalRow::WeekendHours).absolute(),
+        ),
+        (HOUR_RATE, total.value(TotalRow::HourRate).absolute()),
+        (SALARY, total.value(TotalRow::Salary).absolute()),
+        (PAYOUT, total.value(TotalRow::Payout).absolute()),
+        (HOURS, days.range(DayColumn::Hours, total_days)),
+        (BONUS, days.range(DayColumn::Bonus, total_days)),
+        (
+            PLANNED_HOURS,
+            days.range(DayColumn::PlannedHours, total_days),
+        ),
+        (DAY_TYPES, days.range(DayColumn::DayType, total_days)),
+    ];
+    for (name, range) in cells {
+        table.define_name(
+            format!("'{}'!{}", sheet_name, name),
+            &format!("='{}'!{}", sheet_name, range),
+        )?;
+    }
+    Ok(())
+}
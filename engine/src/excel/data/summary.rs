@@ -0,0 +1,218 @@
+use rust_xlsxwriter::{
+    Chart, ChartLegendPosition, ChartType, Formula, Url, utility::column_name_to_number,
+    worksheet::Worksheet,
+};
+
+use crate::excel::{
+    data::names,
+    error::Result as EResult,
+    styles::{CellType, DataType, cell_style},
+    theme::Theme,
+};
+
+pub(crate) const SUMMARY_SHEET_NAME: &str = "Итого за год";
+pub(crate) const YEAR_TOTAL: &str = "Итого";
+pub(crate) const SUMMARY_HEADERS: [&str; 8] = [
+    "Месяц",
+    "Норма часов",
+    "Отработано",
+    "Переработка",
+    "Часы выходных",
+    "Оклад",
+    "Доплата",
+    "К получению",
+];
+
+// Make a cross-sheet reference like 'Sheet name'!Salary
+fn sheet_cell(sheet_name: &str, cell: &str) -> String {
+    format!("'{}'!{}", sheet_name, cell)
+}
+
+pub(crate) fn add_summary_cells(
+    summary_worksheet: &mut Worksheet,
+    title: &str,
+    month_names: &[String],
+    theme: &Theme,
+) -> EResult<()> {
+    let mut format = cell_style(theme, DataType::UsualText, CellType::Header);
+    // Year or years of period
+    summary_worksheet.write_with_format(0, column_name_to_number("A"), title, &format)?;
+    // Sheet title
+    summary_worksheet.merge_range(
+        0,
+        column_name_to_number("B"),
+        0,
+        column_name_to_number("H"),
+        SUMMARY_SHEET_NAME,
+        &format,
+    )?;
+    // Column headers
+    for (column, header) in SUMMARY_HEADERS.into_iter().enumerate() {
+        summary_worksheet.write_with_format(1, column as u16, header, &format)?;
+    }
+
+    // Month rows goes after headers, totals goes after months
+    let total_row = 2 + month_names.len() as u32;
+    let hours_format = cell_style(theme, DataType::UsualText, CellType::Usual);
+    let bonus_format = cell_style(theme, DataType::Money, CellType::TotalBonus);
+    let payment_format = cell_style(theme, DataType::Money, CellType::TotalPayment);
+    for (index, month_name) in month_names.iter().enumerate() {
+        let row = 2 + index as u32;
+        // Month name with link to month sheet
+        summary_worksheet.write_url_with_format(
+            row,
+            column_name_to_number("A"),
+            Url::new(format!("internal:{}", sheet_cell(month_name, "A1"))).set_text(month_name),
+            &hours_format,
+        )?;
+        // Norm hours
+        summary_worksheet.write_formula_with_format(
+            row,
+            column_name_to_number("B"),
+            Formula::new(format!("={}", sheet_cell(month_name, names::NORM_HOURS))),
+            &hours_format,
+        )?;
+        // Worked hours: norm with overtime and weekend hours
+        summary_worksheet.write_formula_with_format(
+            row,
+            column_name_to_number("C"),
+            Formula::new(format!(
+                "=SUM({},{},{})",
+                sheet_cell(month_name, names::NORM_HOURS),
+                sheet_cell(month_name, names::OVERTIME_HOURS),
+                sheet_cell(month_name, names::WEEKEND_HOURS)
+            )),
+            &hours_format,
+        )?;
+        // Overtime hours
+        summary_worksheet.write_formula_with_format(
+            row,
+            column_name_to_number("D"),
+            Formula::new(format!(
+                "={}",
+                sheet_cell(month_name, names::OVERTIME_HOURS)
+            )),
+            &hours_format,
+        )?;
+        // Weekend hours
+        summary_worksheet.write_formula_with_format(
+            row,
+            column_name_to_number("E"),
+            Formula::new(format!("={}", sheet_cell(month_name, names::WEEKEND_HOURS))),
+            &hours_format,
+        )?;
+        // Salary is a payout without bonus, it can be prorated
+        summary_worksheet.write_formula_with_format(
+            row,
+            column_name_to_number("F"),
+            Formula::new(format!(
+                "={}-SUM({})",
+                sheet_cell(month_name, names::PAYOUT),
+                sheet_cell(month_name, names::BONUS)
+            )),
+            &bonus_format,
+        )?;
+        // Bonus
+        summary_worksheet.write_formula_with_format(
+            row,
+            column_name_to_number("G"),
+            Formula::new(format!("=SUM({})", sheet_cell(month_name, names::BONUS))),
+            &bonus_format,
+        )?;
+        // Payout
+        summary_worksheet.write_formula_with_format(
+            row,
+            column_name_to_number("H"),
+            Formula::new(format!("={}", sheet_cell(month_name, names::PAYOUT))),
+            &payment_format,
+        )?;
+    }
+
+    // Year totals
+    summary_worksheet.write_with_format(
+        total_row,
+        column_name_to_number("A"),
+        YEAR_TOTAL,
+        &format,
+    )?;
+    for column in ["B", "C", "D", "E", "F", "G", "H"] {
+        format = match column {
+            "F" | "G" => cell_style(theme, DataType::Money, CellType::TotalBonus),
+            "H" => cell_style(theme, DataType::Money, CellType::TotalPayment),
+            _ => cell_style(theme, DataType::UsualText, CellType::Header),
+        };
+        summary_worksheet.write_formula_with_format(
+            total_row,
+            column_name_to_number(column),
+            Formula::new(format!("=SUM({column}3:{column}{})", total_row)),
+            &format,
+        )?;
+    }
+
+    // Polish worksheet
+    summary_worksheet.set_screen_gridlines(false);
+    summary_worksheet.autofit();
+    summary_worksheet.set_column_width(column_name_to_number("A"), 16)?;
+    summary_worksheet.set_column_range_width(
+        column_name_to_number("B"),
+        column_name_to_number("H"),
+        14,
+    )?;
+    summary_worksheet.set_name(SUMMARY_SHEET_NAME)?;
+
+    // Chart goes under totals
+    let chart = summary_chart(month_names.len() as u32);
+    summary_worksheet.insert_chart(total_row + 2, column_name_to_number("A"), &chart)?;
+    Ok(())
+}
+
+// Salary and bonus as stacked columns, worked and norm hours as lines
+fn summary_chart(total_months: u32) -> Chart {
+    let first_row = 2;
+    let last_row = 1 + total_months;
+    let months = (
+        SUMMARY_SHEET_NAME,
+        first_row,
+        column_name_to_number("A"),
+        last_row,
+        column_name_to_number("A"),
+    );
+    let series = |column: &str| {
+        let column = column_name_to_number(column);
+        (SUMMARY_SHEET_NAME, first_row, column, last_row, column)
+    };
+
+    let mut chart = Chart::new(ChartType::ColumnStacked);
+    chart
+        .add_series()
+        .set_name((SUMMARY_SHEET_NAME, 1, column_name_to_number("F")))
+        .set_categories(months)
+        .set_values(series("F"));
+    chart
+        .add_series()
+        .set_name((SUMMARY_SHEET_NAME, 1, column_name_to_number("G")))
+        .set_categories(months)
+        .set_values(series("G"));
+
+    let mut hours_chart = Chart::new(ChartType::Line);
+    hours_chart
+        .add_series()
+        .set_name((SUMMARY_SHEET_NAME, 1, column_name_to_number("C")))
+        .set_categories(months)
+        .set_values(series("C"))
+        .set_secondary_axis(true);
+    hours_chart
+        .add_series()
+        .set_name((SUMMARY_SHEET_NAME, 1, column_name_to_number("B")))
+        .set_categories(months)
+        .set_values(series("B"))
+        .set_secondary_axis(true);
+    chart.combine(&hours_chart);
+
+    chart.title().set_name("Выплаты и часы по месяцам");
+    chart.y_axis().set_name("Выплата, ₽");
+    chart.y2_axis().set_name("Часы");
+    chart.legend().set_position(ChartLegendPosition::Bottom);
+    chart.set_width(960).set_height(400);
+    chart
+}
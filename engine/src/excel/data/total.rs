@@ -1,39 +1,118 @@
-use anyhow::Result as AResult;
-use rust_xlsxwriter::{Formula, utility::column_name_to_number, worksheet::Worksheet};
+use rust_xlsxwriter::{Formula, worksheet::Worksheet};
 
-use crate::excel::styles::{CellType, DataType, cell_style};
+use crate::excel::{
+    data::{
+        days::{DayType, NOT_EMPLOYED_MARK},
+        names,
+    },
+    error::Result as EResult,
+    layout::{MONTH_LAYOUT, TotalRow},
+    styles::{CellType, DataType, cell_style},
+    theme::Theme,
+};
 
 pub(crate) fn add_total_cells(
     month_worksheet: &mut Worksheet,
-    work_hours: u16,
-    total_days: u8,
-    usual_days_formula: String,
-    weekend_formula: String,
-) -> AResult<()> {
-    let mut format = cell_style(DataType::UsualText, CellType::Header);
-    // Work hours
-    month_worksheet.write_with_format(0, column_name_to_number("E"), work_hours, &format)?;
-    // Overtime hours formula
+    salary: u32,
+    prorated: bool,
+    theme: &Theme,
+) -> EResult<()> {
+    let total = &MONTH_LAYOUT.total;
+    let mut format = cell_style(theme, DataType::UsualText, CellType::Header);
+    // Work hours are sum of planned hours
+    let mut cell = total.value(TotalRow::NormHours);
     month_worksheet.write_formula_with_format(
-        1,
-        column_name_to_number("E"),
-        Formula::new(usual_days_formula),
+        cell.row,
+        cell.col,
+        Formula::new(format!("=SUM({})", names::PLANNED_HOURS)),
         &format,
     )?;
-    // Weekend hours formula
+    // Overtime hours formula, hours at working days
+    cell = total.value(TotalRow::OvertimeHours);
     month_worksheet.write_formula_with_format(
-        2,
-        column_name_to_number("E"),
-        Formula::new(weekend_formula),
+        cell.row,
+        cell.col,
+        Formula::new(format!(
+            "=SUMIF({},\"{}\",{})",
+            names::DAY_TYPES,
+            DayType::Usual.name(),
+            names::HOURS
+        )),
+        &format,
+    )?;
+    // Weekend hours formula, hours at rest days and holidays
+    cell = total.value(TotalRow::WeekendHours);
+    month_worksheet.write_formula_with_format(
+        cell.row,
+        cell.col,
+        Formula::new(format!(
+            "=SUMIF({types},\"{}\",{hours})+SUMIF({types},\"{}\",{hours})",
+            DayType::RestDay.name(),
+            DayType::Holiday.name(),
+            types = names::DAY_TYPES,
+            hours = names::HOURS
+        )),
+        &format,
+    )?;
+
+    format = cell_style(theme, DataType::Money, CellType::Header);
+    // Prorated salary is paid for employed norm, vacation and sick leave count as work days
+    let paid_norm = format!(
+        "({}+{}*(COUNTIF({types},\"{}\")+COUNTIF({types},\"{}\")))",
+        names::NORM_HOURS,
+        names::WORK_DAY_HOURS,
+        DayType::Vacation.name(),
+        DayType::SickLeave.name(),
+        types = names::DAY_TYPES,
+    );
+    // Hour rate formula, norm of whole month is used if salary is prorated,
+    // days out of employment add day type hours
+    let norm = match prorated {
+        true => format!(
+            "({}+{}*COUNTIFS({},\"{}\",{},\"{}\"))",
+            paid_norm,
+            names::WORK_DAY_HOURS,
+            names::DAY_TYPES,
+            DayType::Usual.name(),
+            names::HOURS,
+            NOT_EMPLOYED_MARK
+        ),
+        false => names::NORM_HOURS.to_string(),
+    };
+    cell = total.value(TotalRow::HourRate);
+    month_worksheet.write_formula_with_format(
+        cell.row,
+        cell.col,
+        Formula::new(format!("=IF({norm}>0,{}/{norm},0)", names::SALARY)),
+        &format,
+    )?;
+
+    // Salary input, stays empty if unknown
+    let salary = match salary {
+        0 => "".to_string(),
+        _ => salary.to_string(),
+    };
+    // Only value is unlocked, reader finds month sheets by locked labels
+    format = cell_style(theme, DataType::Money, CellType::InputHeader).set_unlocked();
+    cell = total.value(TotalRow::Salary);
+    month_worksheet.write_formula_with_format(
+        cell.row,
+        cell.col,
+        format!("={}", salary).as_str(),
         &format,
     )?;
 
-    format = cell_style(DataType::Money, CellType::TotalPayment);
-    // Total payment formula
+    format = cell_style(theme, DataType::Money, CellType::TotalPayment);
+    // Total payment formula, prorated salary is paid for employed norm
+    let salary = match prorated {
+        true => format!("{}*{}", names::HOUR_RATE, paid_norm),
+        false => names::SALARY.to_string(),
+    };
+    cell = total.value(TotalRow::Payout);
     month_worksheet.write_formula_with_format(
-        5,
-        column_name_to_number("E"),
-        Formula::new(format!("=SUM(C4:C{})+E5", total_days + 4)),
+        cell.row,
+        cell.col,
+        Formula::new(format!("=SUM({})+{}", names::BONUS, salary)),
         &format,
     )?;
     Ok(())
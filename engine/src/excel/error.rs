@@ -0,0 +1,56 @@
+use derive_more::{Display, Error};
+
+/// Failure underneath engine error, downcast it to tell a timeout from a missing file
+pub type Source = Box<dyn std::error::Error + Send + Sync>;
+
+/// Engine error, variant tells what failed so callers can explain it or retry
+#[derive(Debug, Display, Error)]
+pub enum Error {
+    /// Holiday calendar cannot be downloaded or read, worth retrying later
+    #[display("Cannot fetch calendar: {_0}")]
+    CalendarFetch(#[error(source)] Source),
+    /// Calendar is not a JSON with holidays array
+    #[display("Cannot parse calendar: {_0}")]
+    CalendarParse(#[error(source)] Source),
+    /// Calendar has no holidays of year
+    #[display("Calendar has no data for {_0} year!")]
+    NoDataForYear(#[error(not(source))] i32),
+    /// Table writer failed
+    #[display("Cannot render table: {_0}")]
+    Render(#[error(source)] Source),
+    /// Options are out of range, like period or work day hours
+    #[display("{_0}")]
+    InvalidOptions(#[error(not(source))] String),
+    /// Table read back is not generated by engine or is broken
+    #[display("{_0}")]
+    InvalidTable(#[error(source)] Source),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+// Writers are only used for rendering, so their errors are render failures
+impl From<rust_xlsxwriter::XlsxError> for Error {
+    fn from(error: rust_xlsxwriter::XlsxError) -> Self {
+        Error::Render(Box::new(error))
+    }
+}
+
+impl From<spreadsheet_ods::OdsError> for Error {
+    fn from(error: spreadsheet_ods::OdsError) -> Self {
+        Error::Render(Box::new(error))
+    }
+}
+
+#[cfg(feature = "pdf")]
+impl From<printpdf::Error> for Error {
+    fn from(error: printpdf::Error) -> Self {
+        Error::Render(Box::new(error))
+    }
+}
+
+// Reader is the only calamine user
+impl From<calamine::XlsxError> for Error {
+    fn from(error: calamine::XlsxError) -> Self {
+        Error::InvalidTable(Box::new(error))
+    }
+}
@@ -0,0 +1,259 @@
+use chrono::NaiveDate;
+use serde::{Serialize, Serializer};
+
+use crate::excel::{
+    error::{Error, Result as EResult},
+    render::{JsonRenderer, Renderer},
+    settings::{Locale, PayRules, Schedule, Settings},
+    timesheet::{DayType, Month, MonthTotals, Timesheet},
+};
+
+// Bumped on breaking changes of field names or meaning, new fields keep version
+const SCHEMA_VERSION: u32 = 1;
+
+impl Renderer for JsonRenderer {
+    fn render(&self, timesheet: &Timesheet, settings: &Settings) -> EResult<Vec<u8>> {
+        serde_json::to_vec_pretty(&TimesheetJson::new(timesheet, settings.locale))
+            .map_err(|e| Error::Render(e.into()))
+    }
+}
+
+// Model is serialized through export structs, so inner changes keep schema,
+// month titles are in default locale without settings
+impl Serialize for Timesheet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TimesheetJson::new(self, Locale::default()).serialize(serializer)
+    }
+}
+
+#[derive(Serialize)]
+struct TimesheetJson {
+    schema_version: u32,
+    year: i32,
+    salary: u32,
+    pay_rules: PayRulesJson,
+    schedule: ScheduleJson,
+    months: Vec<MonthJson>,
+}
+
+#[derive(Serialize)]
+struct PayRulesJson {
+    overtime: f64,
+    rest_day: f64,
+    holiday: f64,
+}
+
+#[derive(Serialize)]
+struct ScheduleJson {
+    work_day_hours: f64,
+}
+
+#[derive(Serialize)]
+struct MonthJson {
+    month: u32,
+    title: &'static str,
+    days: Vec<DayJson>,
+    totals: TotalsJson,
+}
+
+#[derive(Serialize)]
+struct DayJson {
+    date: NaiveDate,
+    day_type: DayTypeJson,
+    employed: bool,
+    planned_hours: f64,
+    hours: f64,
+    bonus: f64,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum DayTypeJson {
+    Usual,
+    RestDay,
+    Holiday,
+    Vacation,
+    SickLeave,
+}
+
+#[derive(Serialize)]
+struct TotalsJson {
+    norm_hours: f64,
+    overtime_hours: f64,
+    weekend_hours: f64,
+    worked_hours: f64,
+    hour_rate: f64,
+    salary: f64,
+    bonus: f64,
+    payout: f64,
+}
+
+impl TimesheetJson {
+    fn new(timesheet: &Timesheet, locale: Locale) -> Self {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            year: timesheet.year(),
+            salary: timesheet.salary(),
+            pay_rules: timesheet.pay_rules().into(),
+            schedule: timesheet.schedule().into(),
+            months: timesheet
+                .months()
+                .iter()
+                .map(|month| MonthJson::new(month, timesheet, locale))
+                .collect(),
+        }
+    }
+}
+
+impl From<&PayRules> for PayRulesJson {
+    fn from(pay_rules: &PayRules) -> Self {
+        Self {
+            overtime: pay_rules.overtime,
+            rest_day: pay_rules.rest_day,
+            holiday: pay_rules.holiday,
+        }
+    }
+}
+
+impl From<&Schedule> for ScheduleJson {
+    fn from(schedule: &Schedule) -> Self {
+        Self {
+            work_day_hours: schedule.work_day_hours,
+        }
+    }
+}
+
+impl MonthJson {
+    fn new(month: &Month, timesheet: &Timesheet, locale: Locale) -> Self {
+        let pay_rules = timesheet.pay_rules();
+        let totals = month.totals();
+        let days = month
+            .days()
+            .iter()
+            .zip(month.hours())
+            .map(|(day, hours)| DayJson {
+                date: day.date(),
+                day_type: day.day_type().into(),
+                employed: day.employed(),
+                planned_hours: day.planned_hours(timesheet.schedule()),
+                hours: *hours,
+                bonus: match day.employed() {
+                    true => {
+                        MonthTotals::day_bonus(totals.hour_rate, *hours, day.day_type(), pay_rules)
+                    }
+                    false => 0.0,
+                },
+            })
+            .collect();
+        Self {
+            month: month.number(),
+            title: month.title(locale),
+            days,
+            totals: totals.into(),
+        }
+    }
+}
+
+impl From<DayType> for DayTypeJson {
+    fn from(day_type: DayType) -> Self {
+        match day_type {
+            DayType::Usual => DayTypeJson::Usual,
+            DayType::RestDay => DayTypeJson::RestDay,
+            DayType::Holiday => DayTypeJson::Holiday,
+            DayType::Vacation => DayTypeJson::Vacation,
+            DayType::SickLeave => DayTypeJson::SickLeave,
+        }
+    }
+}
+
+impl From<&MonthTotals> for TotalsJson {
+    fn from(totals: &MonthTotals) -> Self {
+        Self {
+            norm_hours: totals.norm_hours,
+            overtime_hours: totals.overtime_hours,
+            weekend_hours: totals.weekend_hours,
+            worked_hours: totals.worked_hours(),
+            hour_rate: totals.hour_rate,
+            salary: totals.salary,
+            bonus: totals.bonus,
+            payout: totals.payout,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeSet, HashSet};
+
+    use serde_json::Value;
+
+    use super::*;
+    use crate::excel::{data::days::Days, settings::Period};
+
+    fn keys(value: &Value) -> BTreeSet<&str> {
+        value
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn set<'a>(keys: &[&'a str]) -> BTreeSet<&'a str> {
+        keys.iter().copied().collect()
+    }
+
+    #[test]
+    fn schema_keys_are_stable() {
+        let days = Days::new(&Period::month(2026, 3).unwrap(), &HashSet::new());
+        let timesheet = Timesheet::new(&days, 60000, PayRules::default(), Schedule::default());
+        let json = serde_json::to_value(&timesheet).unwrap();
+
+        assert_eq!(json["schema_version"], SCHEMA_VERSION);
+        assert_eq!(
+            keys(&json),
+            set(&[
+                "schema_version",
+                "year",
+                "salary",
+                "pay_rules",
+                "schedule",
+                "months"
+            ])
+        );
+        assert_eq!(
+            keys(&json["pay_rules"]),
+            set(&["overtime", "rest_day", "holiday"])
+        );
+        assert_eq!(keys(&json["schedule"]), set(&["work_day_hours"]));
+        let month = &json["months"][0];
+        assert_eq!(keys(month), set(&["month", "title", "days", "totals"]));
+        assert_eq!(
+            keys(&month["totals"]),
+            set(&[
+                "norm_hours",
+                "overtime_hours",
+                "weekend_hours",
+                "worked_hours",
+                "hour_rate",
+                "salary",
+                "bonus",
+                "payout"
+            ])
+        );
+        let day = &month["days"][0];
+        assert_eq!(
+            keys(day),
+            set(&[
+                "date",
+                "day_type",
+                "employed",
+                "planned_hours",
+                "hours",
+                "bonus"
+            ])
+        );
+        assert_eq!(day["date"], "2026-03-01");
+        assert_eq!(day["day_type"], "rest_day");
+    }
+}
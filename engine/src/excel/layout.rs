@@ -0,0 +1,203 @@
+use rust_xlsxwriter::{
+    ColNum, RowNum,
+    utility::{
+        cell_range_absolute, column_number_to_name, row_col_to_cell, row_col_to_cell_absolute,
+    },
+};
+
+// Position of cell at worksheet
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Cell {
+    pub(crate) row: RowNum,
+    pub(crate) col: ColNum,
+}
+
+impl Cell {
+    pub(crate) const fn new(row: RowNum, col: ColNum) -> Self {
+        Self { row, col }
+    }
+
+    pub(crate) const fn offset(self, rows: RowNum, cols: ColNum) -> Self {
+        Self::new(self.row + rows, self.col + cols)
+    }
+
+    // Address like $E$1
+    pub(crate) fn absolute(&self) -> String {
+        row_col_to_cell_absolute(self.row, self.col)
+    }
+}
+
+// Year and app info at first row, month name at second row
+pub(crate) struct HeaderBlock {
+    pub(crate) origin: Cell,
+    pub(crate) width: ColNum,
+}
+
+impl HeaderBlock {
+    pub(crate) fn year(&self) -> Cell {
+        self.origin
+    }
+
+    // App info takes the rest of first row
+    pub(crate) fn app_info(&self) -> (Cell, Cell) {
+        (
+            self.origin.offset(0, 1),
+            self.origin.offset(0, self.width - 1),
+        )
+    }
+
+    pub(crate) fn month(&self) -> (Cell, Cell) {
+        (
+            self.origin.offset(1, 0),
+            self.origin.offset(1, self.width - 1),
+        )
+    }
+}
+
+// Columns of day block from left to right
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DayColumn {
+    Day,
+    Hours,
+    Bonus,
+    PlannedHours,
+    DayType,
+}
+
+impl DayColumn {
+    pub(crate) const ALL: [DayColumn; 5] = [
+        DayColumn::Day,
+        DayColumn::Hours,
+        DayColumn::Bonus,
+        DayColumn::PlannedHours,
+        DayColumn::DayType,
+    ];
+}
+
+// Header row and a row per day below it
+pub(crate) struct DayBlock {
+    pub(crate) origin: Cell,
+}
+
+impl DayBlock {
+    pub(crate) fn col(&self, column: DayColumn) -> ColNum {
+        self.origin.col + column as ColNum
+    }
+
+    pub(crate) fn first_col(&self) -> ColNum {
+        self.col(DayColumn::Day)
+    }
+
+    pub(crate) fn last_col(&self) -> ColNum {
+        self.col(DayColumn::DayType)
+    }
+
+    pub(crate) fn header(&self, column: DayColumn) -> Cell {
+        Cell::new(self.origin.row, self.col(column))
+    }
+
+    // Day row starts from 1 at first day of month, months are always whole
+    pub(crate) fn cell(&self, column: DayColumn, day_row: u32) -> Cell {
+        Cell::new(self.origin.row + day_row, self.col(column))
+    }
+
+    pub(crate) fn first_row(&self) -> RowNum {
+        self.origin.row + 1
+    }
+
+    pub(crate) fn last_row(&self, total_days: u8) -> RowNum {
+        self.origin.row + total_days as RowNum
+    }
+
+    // Relative address for day formulas like B8
+    pub(crate) fn address(&self, column: DayColumn, day_row: u32) -> String {
+        let cell = self.cell(column, day_row);
+        row_col_to_cell(cell.row, cell.col)
+    }
+
+    // Address with fixed column for conditional formatting like $E8
+    pub(crate) fn row_address(&self, column: DayColumn, day_row: u32) -> String {
+        let cell = self.cell(column, day_row);
+        format!("${}{}", column_number_to_name(cell.col), cell.row + 1)
+    }
+
+    // Absolute range of column days like $B$8:$B$38
+    pub(crate) fn range(&self, column: DayColumn, total_days: u8) -> String {
+        cell_range_absolute(
+            self.first_row(),
+            self.col(column),
+            self.last_row(total_days),
+            self.col(column),
+        )
+    }
+}
+
+// Rows of total block from top to bottom
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum TotalRow {
+    NormHours,
+    OvertimeHours,
+    WeekendHours,
+    HourRate,
+    Salary,
+    Payout,
+}
+
+// Labels column with values at the right
+pub(crate) struct TotalBlock {
+    pub(crate) origin: Cell,
+}
+
+impl TotalBlock {
+    pub(crate) fn label(&self, row: TotalRow) -> Cell {
+        self.origin.offset(row as RowNum, 0)
+    }
+
+    pub(crate) fn value(&self, row: TotalRow) -> Cell {
+        self.origin.offset(row as RowNum, 1)
+    }
+
+    pub(crate) fn value_col(&self) -> ColNum {
+        self.origin.col + 1
+    }
+}
+
+// Title with a coloured entry per row below it
+pub(crate) struct LegendBlock {
+    pub(crate) origin: Cell,
+}
+
+impl LegendBlock {
+    pub(crate) fn title(&self) -> Cell {
+        self.origin
+    }
+
+    // Entry index starts from 0
+    pub(crate) fn entry(&self, index: u32) -> Cell {
+        self.origin.offset(1 + index, 0)
+    }
+}
+
+pub(crate) struct MonthLayout {
+    pub(crate) header: HeaderBlock,
+    pub(crate) total: TotalBlock,
+    pub(crate) days: DayBlock,
+    pub(crate) legend: LegendBlock,
+}
+
+// Every block of month worksheet is rendered against this layout
+pub(crate) const MONTH_LAYOUT: MonthLayout = MonthLayout {
+    header: HeaderBlock {
+        origin: Cell::new(0, 0),
+        width: 3,
+    },
+    total: TotalBlock {
+        origin: Cell::new(0, 3),
+    },
+    days: DayBlock {
+        origin: Cell::new(6, 0),
+    },
+    legend: LegendBlock {
+        origin: Cell::new(6, 6),
+    },
+};
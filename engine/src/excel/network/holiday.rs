@@ -1,37 +1,74 @@
 use std::collections::HashSet;
 
-use anyhow::Result as AResult;
 use chrono::Datelike;
 use chrono::NaiveDate;
 use serde::Deserialize;
 
+use crate::excel::{
+    error::{Error, Result as EResult},
+    settings::CalendarSource,
+};
+
 #[derive(Deserialize, Debug, PartialEq)]
 pub struct FetchedDates {
     holidays: HashSet<NaiveDate>,
 }
 
 impl FetchedDates {
-    pub async fn init() -> AResult<Self> {
-        // Url with holidays array in "year-month-day" format
-        let url = "https://raw.githubusercontent.com/d10xa/holidays-calendar/refs/heads/master/json/calendar.json";
-        // Fetch data
-        let response = reqwest::get(url).await?.error_for_status()?;
-        // Deserialize responce body into FetchedDates struct
-        let mut fetched_dates = response.json::<FetchedDates>().await?;
-        // It will be optimized to current year fetching
-        // Try to find last year
-        let last_year = match fetched_dates.holidays.iter().map(|d| d.year()).max() {
-            Some(year) => year,
-            None => return Err(anyhow::anyhow!("Cannot find last year!")),
-        };
-        // Get all holidays dates at finded year
-        fetched_dates.holidays = fetched_dates
-            .holidays
-            .into_iter()
-            .filter(|d| d.year() == last_year)
-            .collect();
-        // Return
-        Ok(fetched_dates)
+    pub async fn init(source: &CalendarSource) -> EResult<Self> {
+        match source {
+            #[cfg(feature = "http")]
+            CalendarSource::Url(url) => {
+                // Fetch data
+                let response = reqwest::get(url)
+                    .await
+                    .and_then(|response| response.error_for_status())
+                    .map_err(|e| Error::CalendarFetch(e.into()))?;
+                // Deserialize responce body into FetchedDates struct, body can be cut by network too
+                let fetched_dates =
+                    response
+                        .json::<FetchedDates>()
+                        .await
+                        .map_err(|e| match e.is_decode() {
+                            true => Error::CalendarParse(e.into()),
+                            false => Error::CalendarFetch(e.into()),
+                        })?;
+                // Return
+                Ok(fetched_dates)
+            }
+            #[cfg(not(feature = "http"))]
+            CalendarSource::Url(url) => Err(Error::InvalidOptions(format!(
+                "Cannot download calendar {url} without http feature, supply it as file or holidays!"
+            ))),
+            // Local copy of calendar in same format
+            CalendarSource::File(path) => {
+                // Kind of io error is kept, message gets the path
+                let file = std::fs::read(path).map_err(|e| {
+                    let message = format!("{}: {e}", path.display());
+                    Error::CalendarFetch(std::io::Error::new(e.kind(), message).into())
+                })?;
+                serde_json::from_slice(&file).map_err(|e| Error::CalendarParse(e.into()))
+            }
+            CalendarSource::Holidays(holidays) if holidays.is_empty() => Err(
+                Error::InvalidOptions("Calendar must be supplied, holidays are empty!".to_string()),
+            ),
+            CalendarSource::Holidays(holidays) => Ok(FetchedDates {
+                holidays: holidays.iter().copied().collect(),
+            }),
+        }
+    }
+
+    // Last year with holidays, generated by default
+    pub(crate) fn last_year(&self) -> EResult<i32> {
+        match self.holidays.iter().map(|d| d.year()).max() {
+            Some(year) => Ok(year),
+            None => Err(Error::CalendarParse("Calendar has no holidays!".into())),
+        }
+    }
+
+    // Calendar without holidays of year has no data for it
+    pub(crate) fn has_year(&self, year: i32) -> bool {
+        self.holidays.iter().any(|d| d.year() == year)
     }
 
     pub(crate) fn get_holidays(&self) -> HashSet<NaiveDate> {
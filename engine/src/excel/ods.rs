@@ -0,0 +1,709 @@
+use spreadsheet_ods::{
+    CellRef, CellStyle, CellStyleRef, Length, Sheet, ValueFormatNumber, ValueFormatRef, WorkBook,
+    cm,
+    color::Rgb,
+    condition::Condition,
+    format::ValueFormatTrait,
+    formula::{
+        fcellref, fcellref_table, fcellrefa, fcellrefc, frangeref, frangerefa, frangerefa_table,
+    },
+    style::{
+        MasterPage, PageStyle, StyleOrigin, StyleUse, TableStyle, TableStyleRef,
+        stylemap::StyleMap,
+        units::{Border, PrintOrientation, TextAlign},
+    },
+    text::{MetaPageCount, MetaPageNumber, TextP},
+    validation::{Validation, ValidationError},
+    write_ods_buf,
+};
+
+use crate::excel::{
+    data::{
+        days::{Day, DayType, NOT_EMPLOYED, NOT_EMPLOYED_MARK, Season},
+        headers::{APP_INFO, day_label, total_label},
+        legend::{INPUT_LEGEND, LEGEND_TITLE},
+        summary::{SUMMARY_HEADERS, SUMMARY_SHEET_NAME, YEAR_TOTAL},
+    },
+    error::Result as EResult,
+    layout::{Cell, DayColumn, MONTH_LAYOUT, TotalRow},
+    render::{OdsRenderer, Renderer},
+    settings::{Locale, PrintSetup, Settings},
+    styles::{BorderWeight, CellType, DataType, background_rgb, cell_outline},
+    theme::Theme,
+    timesheet::{Month, Timesheet},
+};
+
+// Same borders as thin dotted and medium borders at xlsx
+const THIN_BORDER: Length = Length::Pt(0.75);
+const MEDIUM_BORDER: Length = Length::Pt(1.5);
+
+impl Renderer for OdsRenderer {
+    fn render(&self, timesheet: &Timesheet, settings: &Settings) -> EResult<Vec<u8>> {
+        let mut book = WorkBook::new_empty();
+        let mut styles = OdsStyles::new(&mut book, &settings.theme);
+        // Year summary goes first like at xlsx
+        let mut sheets = vec![summary_sheet(
+            &mut styles,
+            &timesheet.title(),
+            timesheet.months(),
+            settings.locale,
+        )];
+        for (index, month) in timesheet.months().iter().enumerate() {
+            sheets.push(month_sheet(&mut styles, timesheet, index, month, settings));
+        }
+        for sheet in sheets {
+            book.push_sheet(sheet);
+        }
+        let buf = write_ods_buf(&mut book, Vec::new())?;
+        Ok(buf)
+    }
+}
+
+fn rgb(color: u32) -> Rgb<u8> {
+    Rgb::new((color >> 16) as u8, (color >> 8) as u8, color as u8)
+}
+
+// Cell styles are added to workbook once and shared by all sheets
+struct OdsStyles<'a> {
+    book: &'a mut WorkBook,
+    theme: &'a Theme,
+    money: ValueFormatRef,
+}
+
+impl<'a> OdsStyles<'a> {
+    fn new(book: &'a mut WorkBook, theme: &'a Theme) -> Self {
+        let mut money = ValueFormatNumber::new_named("money");
+        money
+            .part_number()
+            .fixed_decimal_places(2)
+            .grouping()
+            .build();
+        money.part_text(" ₽").build();
+        // Common styles can refer only to common formats
+        money.set_origin(StyleOrigin::Styles);
+        money.set_styleuse(StyleUse::Named);
+        let money = book.add_number_format(money);
+        Self { book, theme, money }
+    }
+
+    // Same look as cell_style at xlsx
+    fn cell(&mut self, data_type: DataType, cell_type: CellType) -> CellStyleRef {
+        let name = format!("{:?}{:?}", cell_type, data_type);
+        if self.book.cellstyle(&name).is_none() {
+            let style = self.new_style(&name, data_type, cell_type, false);
+            self.book.add_cellstyle(style);
+        }
+        CellStyleRef::from(name)
+    }
+
+    // Day cell background follows day type cell, like conditional formatting at xlsx.
+    // Condition base cell must name a sheet, so every month has own day styles
+    fn day(
+        &mut self,
+        (sheet_index, sheet_name): (usize, &str),
+        day_type: DayType,
+        left_border: bool,
+    ) -> CellStyleRef {
+        let suffix = if left_border { "Left" } else { "" };
+        let name = format!("Day{:?}{}{}", day_type, suffix, sheet_index);
+        if self.book.cellstyle(&name).is_none() {
+            let days = &MONTH_LAYOUT.days;
+            let type_cell = days.cell(DayColumn::DayType, 1);
+            let base_cell = CellRef::remote(sheet_name, type_cell.row, days.first_col() as u32);
+            let mut style = self.new_style(
+                &name,
+                DataType::UsualText,
+                day_type.cell_type(),
+                left_border,
+            );
+            for flag in DayType::ALL {
+                // Applied style replaces whole cell style, so it keeps borders too
+                let applied_name = format!("{:?}{}", flag.cell_type(), suffix);
+                if self.book.cellstyle(&applied_name).is_none() {
+                    let applied = self.new_style(
+                        &applied_name,
+                        DataType::UsualText,
+                        flag.cell_type(),
+                        left_border,
+                    );
+                    self.book.add_cellstyle(applied);
+                }
+                style.push_stylemap(StyleMap::new(
+                    Condition::is_true_formula(format!(
+                        "{}=\"{}\"",
+                        fcellrefc(type_cell.row, type_cell.col as u32),
+                        flag.name()
+                    )),
+                    CellStyleRef::from(applied_name).into(),
+                    Some(base_cell.clone()),
+                ));
+            }
+            self.book.add_cellstyle(style);
+        }
+        CellStyleRef::from(name)
+    }
+
+    // Page setup of month sheets, same as setup_print at xlsx
+    fn month_page(&mut self, print: &PrintSetup) -> TableStyleRef {
+        let name = "MonthPage";
+        if self.book.tablestyle(name).is_none() {
+            let mut page = PageStyle::new(name);
+            // A4 paper
+            page.set_page_width(cm!(21.0));
+            page.set_page_height(cm!(29.7));
+            page.set_print_orientation(PrintOrientation::Portrait);
+            if print.fit_to_width {
+                // Zero height means as many pages as needed
+                page.style_mut().set_attr("style:scale-to-X", "1");
+                page.style_mut().set_attr("style:scale-to-Y", "0");
+            }
+            let page = self.book.add_pagestyle(page);
+            let mut master = MasterPage::new(name);
+            master.set_pagestyle(&page);
+            // Employee at left and page number at right
+            let employee = print.employee_name.as_deref().unwrap_or_default();
+            let footer = master.footer_mut();
+            footer.set_display(true);
+            footer.add_left(TextP::new().text(employee).into_xmltag());
+            footer.add_right(
+                TextP::new()
+                    .text("Страница ")
+                    .tag(MetaPageNumber::new())
+                    .text(" из ")
+                    .tag(MetaPageCount::new())
+                    .into_xmltag(),
+            );
+            let master = self.book.add_masterpage(master);
+            let mut table = TableStyle::new(name);
+            table.set_master_page(&master);
+            self.book.add_tablestyle(table);
+        }
+        TableStyleRef::from(name)
+    }
+
+    // Style with only top border under the day block
+    fn bottom_border(&mut self) -> CellStyleRef {
+        let name = "BottomBorder";
+        if self.book.cellstyle(name).is_none() {
+            let mut style = CellStyle::new_empty();
+            style.set_name(name);
+            style.set_origin(StyleOrigin::Styles);
+            style.set_styleuse(StyleUse::Named);
+            style.set_border_top(MEDIUM_BORDER, Border::Solid, Rgb::new(0, 0, 0));
+            self.book.add_cellstyle(style);
+        }
+        CellStyleRef::from(name)
+    }
+
+    fn new_style(
+        &self,
+        name: &str,
+        data_type: DataType,
+        cell_type: CellType,
+        left_border: bool,
+    ) -> CellStyle {
+        let mut style = match data_type {
+            DataType::Money => CellStyle::new(name, &self.money),
+            DataType::UsualText => {
+                let mut style = CellStyle::new_empty();
+                style.set_name(name);
+                style
+            }
+        };
+        // Conditional styles must be common styles
+        style.set_origin(StyleOrigin::Styles);
+        style.set_styleuse(StyleUse::Named);
+
+        let (border, bold) = cell_outline(&cell_type);
+        let (width, border) = match border {
+            BorderWeight::Dotted => (THIN_BORDER, Border::Dotted),
+            BorderWeight::Medium => (MEDIUM_BORDER, Border::Solid),
+        };
+        let black = Rgb::new(0, 0, 0);
+        style.set_border_top(width, border, black);
+        style.set_border_bottom(width, border, black);
+        style.set_border_right(width, border, black);
+        if left_border {
+            style.set_border_left(MEDIUM_BORDER, Border::Solid, black);
+        } else {
+            style.set_border_left(width, border, black);
+        }
+        if bold {
+            style.set_font_bold();
+        }
+        if let Some(color) = background_rgb(self.theme, &cell_type) {
+            style.set_background_color(rgb(color));
+        }
+        style.set_text_align(TextAlign::Center);
+        style
+    }
+}
+
+// Formula cell holds zero until spreadsheet recalculates it
+fn set_formula(sheet: &mut Sheet, cell: Cell, formula: String, style: &CellStyleRef) {
+    sheet.set_styled_value(cell.row, cell.col as u32, 0.0, style);
+    sheet.set_formula(cell.row, cell.col as u32, format!("of:={}", formula));
+}
+
+fn merge(sheet: &mut Sheet, (first, last): (Cell, Cell), value: &str, style: &CellStyleRef) {
+    sheet.set_styled_value(first.row, first.col as u32, value, style);
+    sheet.set_col_span(
+        first.row,
+        first.col as u32,
+        (last.col - first.col + 1) as u32,
+    );
+}
+
+// Reference like [.E1], ods has no defined names so formulas refer to layout cells
+fn total_ref(row: TotalRow) -> String {
+    let cell = MONTH_LAYOUT.total.value(row);
+    fcellref(cell.row, cell.col as u32)
+}
+
+// Absolute range of day column like [.$B$8:.$B$38]
+fn day_range(column: DayColumn, total_days: u8) -> String {
+    let days = &MONTH_LAYOUT.days;
+    let col = days.col(column) as u32;
+    frangerefa(days.first_row(), col, days.last_row(total_days), col)
+}
+
+fn month_sheet(
+    styles: &mut OdsStyles,
+    timesheet: &Timesheet,
+    sheet_index: usize,
+    month: &Month,
+    settings: &Settings,
+) -> Sheet {
+    let first_day = month.first_day();
+    let total_days = month.days().len() as u8;
+    let days = &MONTH_LAYOUT.days;
+    let locale = settings.locale;
+    let sheet_name = month.name(locale);
+    let mut sheet = Sheet::new(&sheet_name);
+    sheet.set_style(&styles.month_page(&settings.print));
+
+    add_header_cells(&mut sheet, styles, first_day, locale);
+    for (index, day) in month.days().iter().zip(month.hours()).enumerate() {
+        add_day_cells(
+            &mut sheet,
+            styles,
+            (sheet_index, &sheet_name),
+            (day, index as u32 + 1),
+            timesheet,
+            locale,
+        );
+    }
+    add_total_cells(
+        &mut sheet,
+        styles,
+        (month.salary(), month.prorated()),
+        (total_days, timesheet.schedule().work_day_hours),
+    );
+    add_legend_cells(&mut sheet, styles, month.prorated());
+
+    // Dropdown with day types
+    let names = DayType::ALL.map(|day_type| day_type.name().to_string());
+    let type_col = days.col(DayColumn::DayType) as u32;
+    let mut validation = Validation::new();
+    validation.set_condition(Condition::content_is_in_list(&names));
+    validation.set_base_cell(CellRef::local(days.first_row(), type_col));
+    let mut error = ValidationError::new();
+    error.set_title(Some(day_label(DayColumn::DayType, locale).to_string()));
+    validation.set_err(Some(error));
+    let validation = styles.book.add_validation(validation);
+    // Days out of employment keep their type
+    let (first_day_row, last_day_row) = month.employed_rows();
+    for day_row in first_day_row..=last_day_row {
+        let row = days.cell(DayColumn::DayType, day_row).row;
+        sheet.set_validation(row, type_col, &validation);
+    }
+
+    // Wider border at bottom of days block
+    let border_row = days.last_row(total_days) + 1;
+    let style = styles.bottom_border();
+    for col in days.first_col()..=days.last_col() {
+        sheet.set_styled_value(border_row, col as u32, "", &style);
+    }
+
+    // There is no autofit at ods, widths fit the longest labels
+    let widths = [
+        (days.col(DayColumn::Day), cm!(2.4)),
+        (days.col(DayColumn::Hours), cm!(1.6)),
+        (days.col(DayColumn::Bonus), cm!(2.4)),
+        (days.col(DayColumn::PlannedHours), cm!(3.6)),
+        (days.col(DayColumn::DayType), cm!(2.6)),
+        (MONTH_LAYOUT.legend.title().col, cm!(2.6)),
+    ];
+    for (col, width) in widths {
+        sheet.set_col_width(col as u32, width);
+    }
+    if settings.print.repeat_header {
+        let header_row = days.header(DayColumn::Day).row;
+        sheet.set_header_rows(header_row, header_row);
+    }
+    sheet
+}
+
+fn add_header_cells(sheet: &mut Sheet, styles: &mut OdsStyles, first_day: &Day, locale: Locale) {
+    let header = &MONTH_LAYOUT.header;
+    let days = &MONTH_LAYOUT.days;
+    let total = &MONTH_LAYOUT.total;
+    let mut style = styles.cell(DataType::UsualText, CellType::Header);
+    let year = header.year();
+    sheet.set_styled_value(year.row, year.col as u32, first_day.year(), &style);
+    merge(sheet, header.app_info(), APP_INFO, &style);
+    for row in [
+        TotalRow::NormHours,
+        TotalRow::OvertimeHours,
+        TotalRow::WeekendHours,
+        TotalRow::HourRate,
+    ] {
+        let cell = total.label(row);
+        sheet.set_styled_value(cell.row, cell.col as u32, total_label(row, locale), &style);
+    }
+    for column in DayColumn::ALL {
+        style = match column {
+            DayColumn::Day | DayColumn::Bonus => styles.cell(DataType::UsualText, CellType::Header),
+            DayColumn::Hours | DayColumn::PlannedHours | DayColumn::DayType => {
+                styles.cell(DataType::UsualText, CellType::InputHeader)
+            }
+        };
+        let cell = days.header(column);
+        sheet.set_styled_value(cell.row, cell.col as u32, day_label(column, locale), &style);
+    }
+    style = styles.cell(DataType::UsualText, CellType::InputHeader);
+    let mut cell = total.label(TotalRow::Salary);
+    sheet.set_styled_value(
+        cell.row,
+        cell.col as u32,
+        total_label(TotalRow::Salary, locale),
+        &style,
+    );
+    style = styles.cell(DataType::UsualText, CellType::TotalPayment);
+    cell = total.label(TotalRow::Payout);
+    sheet.set_styled_value(
+        cell.row,
+        cell.col as u32,
+        total_label(TotalRow::Payout, locale),
+        &style,
+    );
+
+    let cell_type = match first_day.season() {
+        Season::Winter => CellType::MonthWinter,
+        Season::Spring => CellType::MonthSpring,
+        Season::Summer => CellType::MonthSummer,
+        Season::Autumn => CellType::MonthAutumn,
+    };
+    style = styles.cell(DataType::UsualText, cell_type);
+    merge(sheet, header.month(), &first_day.month_name(locale), &style);
+}
+
+fn add_day_cells(
+    sheet: &mut Sheet,
+    styles: &mut OdsStyles,
+    month_sheet: (usize, &str),
+    ((day, worked_hours), day_row): ((&Day, &f64), u32),
+    timesheet: &Timesheet,
+    locale: Locale,
+) {
+    // Days out of employment are greyed out without day type styles
+    if !day.employed() {
+        return add_not_employed_cells(sheet, styles, (day, day_row), locale);
+    }
+    let days = &MONTH_LAYOUT.days;
+    let type_cell = days.cell(DayColumn::DayType, day_row);
+    let day_type = fcellref(type_cell.row, type_cell.col as u32);
+
+    let style = styles.day(month_sheet, day.day_type(), false);
+    let mut cell = days.cell(DayColumn::Hours, day_row);
+    sheet.set_styled_value(cell.row, cell.col as u32, *worked_hours, &style);
+    cell = days.cell(DayColumn::PlannedHours, day_row);
+    match day.corrected_planned_hours() {
+        Some(hours) => sheet.set_styled_value(cell.row, cell.col as u32, hours, &style),
+        None => set_formula(
+            sheet,
+            cell,
+            format!(
+                "IF({}=\"{}\";{};0)",
+                day_type,
+                DayType::Usual.name(),
+                timesheet.schedule().work_day_hours
+            ),
+            &style,
+        ),
+    }
+    sheet.set_styled_value(
+        type_cell.row,
+        type_cell.col as u32,
+        day.day_type().name(),
+        &style,
+    );
+    cell = days.cell(DayColumn::Day, day_row);
+    sheet.set_styled_value(
+        cell.row,
+        cell.col as u32,
+        format!("{} {}", day.number(), day.weekday_short(locale)),
+        &styles.day(month_sheet, day.day_type(), true),
+    );
+
+    // Multipliers are written as numbers, nested IF ends with zero for unknown type
+    let multiplier = DayType::ALL
+        .iter()
+        .rev()
+        .fold("0".to_string(), |otherwise, flag| {
+            format!(
+                "IF({}=\"{}\";{};{})",
+                day_type,
+                flag.name(),
+                flag.multiplier(timesheet.pay_rules()),
+                otherwise
+            )
+        });
+    let hour_rate = MONTH_LAYOUT.total.value(TotalRow::HourRate);
+    let hours = days.cell(DayColumn::Hours, day_row);
+    set_formula(
+        sheet,
+        days.cell(DayColumn::Bonus, day_row),
+        format!(
+            "{}*{}*{}",
+            fcellrefa(hour_rate.row, hour_rate.col as u32),
+            fcellref(hours.row, hours.col as u32),
+            multiplier
+        ),
+        &styles.cell(DataType::Money, CellType::TotalBonus),
+    );
+}
+
+// Hours are marked as not worked, day type stays for month norm
+fn add_not_employed_cells(
+    sheet: &mut Sheet,
+    styles: &mut OdsStyles,
+    (day, day_row): (&Day, u32),
+    locale: Locale,
+) {
+    let days = &MONTH_LAYOUT.days;
+    let style = styles.cell(DataType::UsualText, CellType::NotEmployed);
+    let mut cell = days.cell(DayColumn::Hours, day_row);
+    sheet.set_styled_value(cell.row, cell.col as u32, NOT_EMPLOYED_MARK, &style);
+    cell = days.cell(DayColumn::PlannedHours, day_row);
+    sheet.set_styled_value(cell.row, cell.col as u32, 0, &style);
+    cell = days.cell(DayColumn::DayType, day_row);
+    sheet.set_styled_value(cell.row, cell.col as u32, day.day_type().name(), &style);
+    cell = days.cell(DayColumn::Day, day_row);
+    sheet.set_styled_value(
+        cell.row,
+        cell.col as u32,
+        format!("{} {}", day.number(), day.weekday_short(locale)),
+        &style,
+    );
+    cell = days.cell(DayColumn::Bonus, day_row);
+    sheet.set_styled_value(
+        cell.row,
+        cell.col as u32,
+        0,
+        &styles.cell(DataType::Money, CellType::TotalBonus),
+    );
+}
+
+fn add_total_cells(
+    sheet: &mut Sheet,
+    styles: &mut OdsStyles,
+    (salary, prorated): (u32, bool),
+    (total_days, work_day_hours): (u8, f64),
+) {
+    let total = &MONTH_LAYOUT.total;
+    let hours = day_range(DayColumn::Hours, total_days);
+    let types = day_range(DayColumn::DayType, total_days);
+    let mut style = styles.cell(DataType::UsualText, CellType::Header);
+    set_formula(
+        sheet,
+        total.value(TotalRow::NormHours),
+        format!("SUM({})", day_range(DayColumn::PlannedHours, total_days)),
+        &style,
+    );
+    set_formula(
+        sheet,
+        total.value(TotalRow::OvertimeHours),
+        format!("SUMIF({};\"{}\";{})", types, DayType::Usual.name(), hours),
+        &style,
+    );
+    set_formula(
+        sheet,
+        total.value(TotalRow::WeekendHours),
+        format!(
+            "SUMIF({types};\"{}\";{hours})+SUMIF({types};\"{}\";{hours})",
+            DayType::RestDay.name(),
+            DayType::Holiday.name(),
+        ),
+        &style,
+    );
+    style = styles.cell(DataType::Money, CellType::Header);
+    // Prorated salary is paid for employed norm, vacation and sick leave count as work days
+    let paid_norm = format!(
+        "({}+{}*(COUNTIF({types};\"{}\")+COUNTIF({types};\"{}\")))",
+        total_ref(TotalRow::NormHours),
+        work_day_hours,
+        DayType::Vacation.name(),
+        DayType::SickLeave.name(),
+    );
+    // Norm of whole month is used if salary is prorated, days out of employment add day type hours
+    let norm = match prorated {
+        true => format!(
+            "({}+{}*COUNTIFS({};\"{}\";{};\"{}\"))",
+            paid_norm,
+            work_day_hours,
+            types,
+            DayType::Usual.name(),
+            hours,
+            NOT_EMPLOYED_MARK
+        ),
+        false => total_ref(TotalRow::NormHours),
+    };
+    set_formula(
+        sheet,
+        total.value(TotalRow::HourRate),
+        format!("IF({norm}>0;{}/{norm};0)", total_ref(TotalRow::Salary)),
+        &style,
+    );
+    // Salary input, stays empty if unknown
+    style = styles.cell(DataType::Money, CellType::InputHeader);
+    let cell = total.value(TotalRow::Salary);
+    match salary {
+        0 => sheet.set_styled_value(cell.row, cell.col as u32, "", &style),
+        _ => sheet.set_styled_value(cell.row, cell.col as u32, salary, &style),
+    }
+    style = styles.cell(DataType::Money, CellType::TotalPayment);
+    // Prorated salary is paid for employed norm
+    let salary = match prorated {
+        true => format!("{}*{}", total_ref(TotalRow::HourRate), paid_norm),
+        false => total_ref(TotalRow::Salary),
+    };
+    set_formula(
+        sheet,
+        total.value(TotalRow::Payout),
+        format!(
+            "SUM({})+{}",
+            day_range(DayColumn::Bonus, total_days),
+            salary
+        ),
+        &style,
+    );
+}
+
+fn add_legend_cells(sheet: &mut Sheet, styles: &mut OdsStyles, prorated: bool) {
+    let legend = &MONTH_LAYOUT.legend;
+    let mut style = styles.cell(DataType::UsualText, CellType::Header);
+    let mut cell = legend.title();
+    sheet.set_styled_value(cell.row, cell.col as u32, LEGEND_TITLE, &style);
+    for (index, day_type) in DayType::ALL.iter().enumerate() {
+        style = styles.cell(DataType::UsualText, day_type.cell_type());
+        cell = legend.entry(index as u32);
+        sheet.set_styled_value(cell.row, cell.col as u32, day_type.name(), &style);
+    }
+    style = styles.cell(DataType::UsualText, CellType::InputHeader);
+    cell = legend.entry(DayType::ALL.len() as u32);
+    sheet.set_styled_value(cell.row, cell.col as u32, INPUT_LEGEND, &style);
+    if prorated {
+        style = styles.cell(DataType::UsualText, CellType::NotEmployed);
+        cell = legend.entry(DayType::ALL.len() as u32 + 1);
+        sheet.set_styled_value(cell.row, cell.col as u32, NOT_EMPLOYED, &style);
+    }
+}
+
+fn summary_sheet(styles: &mut OdsStyles, title: &str, months: &[Month], locale: Locale) -> Sheet {
+    let mut sheet = Sheet::new(SUMMARY_SHEET_NAME);
+    let mut style = styles.cell(DataType::UsualText, CellType::Header);
+    sheet.set_styled_value(0, 0, title, &style);
+    merge(
+        &mut sheet,
+        (
+            Cell::new(0, 1),
+            Cell::new(0, SUMMARY_HEADERS.len() as u16 - 1),
+        ),
+        SUMMARY_SHEET_NAME,
+        &style,
+    );
+    for (col, header) in SUMMARY_HEADERS.into_iter().enumerate() {
+        sheet.set_styled_value(1, col as u32, header, &style);
+    }
+
+    // Month rows goes after headers, totals goes after months
+    let total_row = 2 + months.len() as u32;
+    let hours_style = styles.cell(DataType::UsualText, CellType::Usual);
+    let bonus_style = styles.cell(DataType::Money, CellType::TotalBonus);
+    let payment_style = styles.cell(DataType::Money, CellType::TotalPayment);
+    for (index, month) in months.iter().enumerate() {
+        let row = 2 + index as u32;
+        let month_name = month.name(locale);
+        let value = |total_row: TotalRow| {
+            let cell = MONTH_LAYOUT.total.value(total_row);
+            fcellref_table(month_name.as_str(), cell.row, cell.col as u32)
+        };
+        let days = &MONTH_LAYOUT.days;
+        let bonus_col = days.col(DayColumn::Bonus) as u32;
+        let bonus = format!(
+            "SUM({})",
+            frangerefa_table(
+                month_name.as_str(),
+                days.first_row(),
+                bonus_col,
+                days.last_row(month.days().len() as u8),
+                bonus_col
+            )
+        );
+        // Month name with link to month sheet
+        set_formula(
+            &mut sheet,
+            Cell::new(row, 0),
+            format!("HYPERLINK(\"#'{}'.A1\";\"{}\")", month_name, month_name),
+            &hours_style,
+        );
+        sheet.set_value(row, 0, month_name.as_str());
+        let columns = [
+            (value(TotalRow::NormHours), &hours_style),
+            (
+                format!(
+                    "SUM({};{};{})",
+                    value(TotalRow::NormHours),
+                    value(TotalRow::OvertimeHours),
+                    value(TotalRow::WeekendHours)
+                ),
+                &hours_style,
+            ),
+            (value(TotalRow::OvertimeHours), &hours_style),
+            (value(TotalRow::WeekendHours), &hours_style),
+            // Salary is a payout without bonus, it can be prorated
+            (
+                format!("{}-{}", value(TotalRow::Payout), bonus),
+                &bonus_style,
+            ),
+            (bonus, &bonus_style),
+            (value(TotalRow::Payout), &payment_style),
+        ];
+        for (col, (formula, style)) in columns.into_iter().enumerate() {
+            set_formula(&mut sheet, Cell::new(row, col as u16 + 1), formula, style);
+        }
+    }
+
+    // Year totals
+    sheet.set_styled_value(total_row, 0, YEAR_TOTAL, &style);
+    for col in 1..SUMMARY_HEADERS.len() as u32 {
+        style = match col {
+            5 | 6 => bonus_style.clone(),
+            7 => payment_style.clone(),
+            _ => styles.cell(DataType::UsualText, CellType::Header),
+        };
+        set_formula(
+            &mut sheet,
+            Cell::new(total_row, col as u16),
+            format!("SUM({})", frangeref(2, col, total_row - 1, col)),
+            &style,
+        );
+    }
+
+    sheet.set_col_width(0, cm!(4));
+    for col in 1..SUMMARY_HEADERS.len() as u32 {
+        sheet.set_col_width(col, cm!(3));
+    }
+    sheet
+}
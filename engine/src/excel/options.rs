@@ -0,0 +1,80 @@
+use serde::Deserialize;
+
+use crate::excel::{
+    builder::TimesheetBuilder,
+    error::Result as EResult,
+    settings::{OutputFormat, PayRules},
+};
+
+/// Generation options as JSON of API request or page, missing ones are the same as at bot
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TimesheetOptions {
+    #[serde(default)]
+    pub salary: u32,
+    pub year: Option<i32>,
+    /// Format name like "xlsx", xlsx if none
+    pub format: Option<String>,
+    pub rules: Option<RulesOptions>,
+}
+
+/// Pay multipliers, missing ones are default
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct RulesOptions {
+    pub overtime: Option<f64>,
+    pub rest_day: Option<f64>,
+    pub holiday: Option<f64>,
+}
+
+impl TimesheetOptions {
+    pub fn format(&self) -> EResult<OutputFormat> {
+        match &self.format {
+            Some(format) => format.parse(),
+            None => Ok(OutputFormat::default()),
+        }
+    }
+
+    pub fn pay_rules(&self) -> PayRules {
+        let default = PayRules::default();
+        match self.rules {
+            Some(rules) => PayRules {
+                overtime: rules.overtime.unwrap_or(default.overtime),
+                rest_day: rules.rest_day.unwrap_or(default.rest_day),
+                holiday: rules.holiday.unwrap_or(default.holiday),
+            },
+            None => default,
+        }
+    }
+
+    /// Builder with these options, calendar and the rest are left to caller
+    pub fn builder(&self) -> EResult<TimesheetBuilder> {
+        let mut builder = TimesheetBuilder::new(self.salary)
+            .pay_rules(self.pay_rules())
+            .format(self.format()?);
+        if let Some(year) = self.year {
+            builder = builder.year(year);
+        }
+        Ok(builder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_options_are_default() {
+        let options: TimesheetOptions =
+            serde_json::from_str(r#"{"format": "ODS", "rules": {"holiday": 3}}"#).unwrap();
+        assert_eq!(options.salary, 0);
+        assert_eq!(options.format().unwrap(), OutputFormat::Ods);
+        assert_eq!(
+            options.pay_rules(),
+            PayRules {
+                holiday: 3.0,
+                ..Default::default()
+            }
+        );
+        let options: TimesheetOptions = serde_json::from_str(r#"{"format": "doc"}"#).unwrap();
+        assert!(options.builder().is_err());
+    }
+}
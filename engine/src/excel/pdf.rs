@@ -0,0 +1,512 @@
+use printpdf::{
+    Color, IndirectFontRef, Line, LineDashPattern, Mm, PdfDocument, PdfDocumentReference,
+    PdfLayerReference, Point, Rect, Rgb, path::PaintMode,
+};
+use ttf_parser::Face;
+
+use crate::excel::{
+    data::{
+        days::{DayType, NOT_EMPLOYED, NOT_EMPLOYED_MARK, Season},
+        headers::{APP_INFO, day_label, total_label},
+        legend::{INPUT_LEGEND, LEGEND_TITLE},
+        summary::{SUMMARY_HEADERS, SUMMARY_SHEET_NAME, YEAR_TOTAL},
+    },
+    error::{Error, Result as EResult},
+    layout::{Cell, DayColumn, MONTH_LAYOUT, TotalRow},
+    render::{PdfRenderer, Renderer},
+    settings::{Locale, Settings},
+    styles::{BorderWeight, CellType, background_rgb, cell_outline},
+    theme::Theme,
+    timesheet::{Month, MonthTotals, Timesheet},
+};
+
+// DejaVu has cyrillic glyphs, fonts are subset at saving
+const REGULAR_FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans.ttf");
+const BOLD_FONT: &[u8] = include_bytes!("../../assets/fonts/DejaVuSans-Bold.ttf");
+
+// A4 portrait, sizes in millimetres
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+const ROW_HEIGHT: f32 = 6.5;
+const FONT_SIZE: f32 = 9.0;
+const FOOTER_FONT_SIZE: f32 = 8.0;
+const PT_IN_MM: f32 = 0.3528;
+
+// Widths of month page blocks, a column shared by blocks takes the widest one
+const TOTAL_LABEL_WIDTH: f32 = 38.0;
+const TOTAL_VALUE_WIDTH: f32 = 30.0;
+const LEGEND_WIDTH: f32 = 30.0;
+// Columns out of blocks are gaps between them
+const GAP_WIDTH: f32 = 6.0;
+const SUMMARY_COLUMNS: [f32; 8] = [26.0, 22.0, 22.0, 22.0, 22.0, 22.0, 22.0, 22.0];
+
+impl Renderer for PdfRenderer {
+    fn render(&self, timesheet: &Timesheet, settings: &Settings) -> EResult<Vec<u8>> {
+        // Document info strings are not encoded, so title stays ascii
+        let (doc, page, layer) = PdfDocument::new(
+            format!("tabel_{}", timesheet.title()),
+            Mm(PAGE_WIDTH),
+            Mm(PAGE_HEIGHT),
+            "Layer",
+        );
+        let fonts = Fonts::new(&doc)?;
+        let total_pages = timesheet.months().len() + 1;
+
+        // Year summary goes first like at xlsx
+        let pdf_page = PdfPage {
+            layer: doc.get_page(page).get_layer(layer),
+            fonts: &fonts,
+            theme: &settings.theme,
+            locale: settings.locale,
+            columns: &SUMMARY_COLUMNS,
+        };
+        add_summary_cells(&pdf_page, timesheet);
+        add_footer(&pdf_page, settings, 1, total_pages);
+
+        let month_columns = month_columns();
+        for (index, month) in timesheet.months().iter().enumerate() {
+            let (page, layer) = doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "Layer");
+            let pdf_page = PdfPage {
+                layer: doc.get_page(page).get_layer(layer),
+                fonts: &fonts,
+                theme: &settings.theme,
+                locale: settings.locale,
+                columns: &month_columns,
+            };
+            add_month_cells(&pdf_page, timesheet, month);
+            add_footer(&pdf_page, settings, index + 2, total_pages);
+        }
+        let buf = doc.save_to_bytes()?;
+        Ok(buf)
+    }
+}
+
+struct Fonts {
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    regular_face: Face<'static>,
+    bold_face: Face<'static>,
+}
+
+impl Fonts {
+    fn new(doc: &PdfDocumentReference) -> EResult<Self> {
+        Ok(Self {
+            regular: doc.add_external_font_with_subsetting(REGULAR_FONT, true)?,
+            bold: doc.add_external_font_with_subsetting(BOLD_FONT, true)?,
+            regular_face: Face::parse(REGULAR_FONT, 0).map_err(|e| Error::Render(e.into()))?,
+            bold_face: Face::parse(BOLD_FONT, 0).map_err(|e| Error::Render(e.into()))?,
+        })
+    }
+
+    // Text width in millimetres, used for centering
+    fn text_width(&self, text: &str, size: f32, bold: bool) -> f32 {
+        let face = if bold {
+            &self.bold_face
+        } else {
+            &self.regular_face
+        };
+        let units: u32 = text
+            .chars()
+            .filter_map(|c| face.glyph_index(c))
+            .filter_map(|glyph| face.glyph_hor_advance(glyph))
+            .map(u32::from)
+            .sum();
+        units as f32 / face.units_per_em() as f32 * size * PT_IN_MM
+    }
+}
+
+fn color(color: u32) -> Color {
+    Color::Rgb(Rgb::new(
+        ((color >> 16) & 0xFF) as f32 / 255.0,
+        ((color >> 8) & 0xFF) as f32 / 255.0,
+        (color & 0xFF) as f32 / 255.0,
+        None,
+    ))
+}
+
+// Hours like 8 or 7.5
+fn hours(value: f64) -> String {
+    format!("{}", value)
+}
+
+// Money like 50 000,00 ₽
+fn money(value: f64) -> String {
+    let cents = (value * 100.0).round() as i64;
+    let digits = (cents.abs() / 100).to_string();
+    let mut grouped = String::new();
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(' ');
+        }
+        grouped.push(digit);
+    }
+    let sign = if cents < 0 { "-" } else { "" };
+    format!("{}{},{:02} ₽", sign, grouped, cents.abs() % 100)
+}
+
+fn day_column_width(column: DayColumn) -> f32 {
+    match column {
+        DayColumn::Day => 26.0,
+        DayColumn::Hours => 16.0,
+        DayColumn::Bonus => 28.0,
+        DayColumn::PlannedHours => 38.0,
+        DayColumn::DayType => 30.0,
+    }
+}
+
+// Column widths of month page follow month layout, so moved blocks keep their widths
+fn month_columns() -> Vec<f32> {
+    let days = &MONTH_LAYOUT.days;
+    let total = &MONTH_LAYOUT.total;
+    let mut widths: Vec<(u16, f32)> = DayColumn::ALL
+        .into_iter()
+        .map(|column| (days.col(column), day_column_width(column)))
+        .collect();
+    widths.push((total.label(TotalRow::NormHours).col, TOTAL_LABEL_WIDTH));
+    widths.push((total.value_col(), TOTAL_VALUE_WIDTH));
+    widths.push((MONTH_LAYOUT.legend.title().col, LEGEND_WIDTH));
+    let last_col = widths.iter().map(|(col, _)| *col).max().unwrap_or(0);
+    let mut columns = vec![0.0; last_col as usize + 1];
+    for (col, width) in widths {
+        columns[col as usize] = f32::max(columns[col as usize], width);
+    }
+    for width in columns.iter_mut().filter(|width| **width == 0.0) {
+        *width = GAP_WIDTH;
+    }
+    columns
+}
+
+// Page is a grid of rows with fixed height and columns of given widths
+struct PdfPage<'a> {
+    layer: PdfLayerReference,
+    fonts: &'a Fonts,
+    theme: &'a Theme,
+    locale: Locale,
+    columns: &'a [f32],
+}
+
+impl PdfPage<'_> {
+    fn left(&self, col: u16) -> f32 {
+        MARGIN + self.columns[..col as usize].iter().sum::<f32>()
+    }
+
+    fn bottom(&self, row: u32) -> f32 {
+        PAGE_HEIGHT - MARGIN - (row + 1) as f32 * ROW_HEIGHT
+    }
+
+    fn line(&self, from: (f32, f32), to: (f32, f32), weight: BorderWeight) {
+        let (thickness, dash_pattern) = match weight {
+            BorderWeight::Dotted => (
+                0.5,
+                LineDashPattern {
+                    dash_1: Some(1),
+                    gap_1: Some(1),
+                    ..Default::default()
+                },
+            ),
+            BorderWeight::Medium => (1.5, LineDashPattern::default()),
+        };
+        self.layer.set_outline_color(color(0x000000));
+        self.layer.set_outline_thickness(thickness);
+        self.layer.set_line_dash_pattern(dash_pattern);
+        self.layer.add_line(Line {
+            points: vec![
+                (Point::new(Mm(from.0), Mm(from.1)), false),
+                (Point::new(Mm(to.0), Mm(to.1)), false),
+            ],
+            is_closed: false,
+        });
+    }
+
+    // Same look as cell_style at xlsx, text is centered
+    fn cell(&self, first: Cell, last_col: u16, text: &str, cell_type: CellType) {
+        let left = self.left(first.col);
+        let right = self.left(last_col + 1);
+        let bottom = self.bottom(first.row);
+        let top = bottom + ROW_HEIGHT;
+        if let Some(background) = background_rgb(self.theme, &cell_type) {
+            self.layer.set_fill_color(color(background));
+            self.layer.add_rect(
+                Rect::new(Mm(left), Mm(bottom), Mm(right), Mm(top)).with_mode(PaintMode::Fill),
+            );
+        }
+        let (border, bold) = cell_outline(&cell_type);
+        self.line((left, bottom), (right, bottom), border);
+        self.line((right, bottom), (right, top), border);
+        self.line((right, top), (left, top), border);
+        self.line((left, top), (left, bottom), border);
+
+        let font = if bold {
+            &self.fonts.bold
+        } else {
+            &self.fonts.regular
+        };
+        let width = self.fonts.text_width(text, FONT_SIZE, bold);
+        // Baseline is lowered by a half of capital letter height
+        let baseline = bottom + ROW_HEIGHT / 2.0 - FONT_SIZE * PT_IN_MM * 0.35;
+        self.layer.set_fill_color(color(0x000000));
+        self.layer.use_text(
+            text,
+            FONT_SIZE,
+            Mm(left + (right - left - width) / 2.0),
+            Mm(baseline),
+            font,
+        );
+    }
+}
+
+fn add_month_cells(page: &PdfPage, timesheet: &Timesheet, month: &Month) {
+    let header = &MONTH_LAYOUT.header;
+    let total = &MONTH_LAYOUT.total;
+    let days = &MONTH_LAYOUT.days;
+    let legend = &MONTH_LAYOUT.legend;
+    let first_day = month.first_day();
+    let totals = month.totals();
+    let salary = month.salary();
+
+    // Header
+    page.cell(
+        header.year(),
+        header.year().col,
+        &first_day.year().to_string(),
+        CellType::Header,
+    );
+    let (first, last) = header.app_info();
+    page.cell(first, last.col, APP_INFO, CellType::Header);
+    let cell_type = match first_day.season() {
+        Season::Winter => CellType::MonthWinter,
+        Season::Spring => CellType::MonthSpring,
+        Season::Summer => CellType::MonthSummer,
+        Season::Autumn => CellType::MonthAutumn,
+    };
+    let (first, last) = header.month();
+    page.cell(
+        first,
+        last.col,
+        first_day.month_title(page.locale),
+        cell_type,
+    );
+
+    // Totals
+    // Salary of whole month like input cell at xlsx, payout is prorated
+    let salary_text = match salary {
+        0 => "".to_string(),
+        _ => money(salary as f64),
+    };
+    let rows = [
+        (
+            TotalRow::NormHours,
+            hours(totals.norm_hours),
+            CellType::Header,
+            CellType::Header,
+        ),
+        (
+            TotalRow::OvertimeHours,
+            hours(totals.overtime_hours),
+            CellType::Header,
+            CellType::Header,
+        ),
+        (
+            TotalRow::WeekendHours,
+            hours(totals.weekend_hours),
+            CellType::Header,
+            CellType::Header,
+        ),
+        (
+            TotalRow::HourRate,
+            money(totals.hour_rate),
+            CellType::Header,
+            CellType::Header,
+        ),
+        (
+            TotalRow::Salary,
+            salary_text,
+            CellType::InputHeader,
+            CellType::InputHeader,
+        ),
+        (
+            TotalRow::Payout,
+            money(totals.payout),
+            CellType::TotalPayment,
+            CellType::TotalPayment,
+        ),
+    ];
+    for (row, value, label_type, value_type) in rows {
+        let cell = total.label(row);
+        page.cell(cell, cell.col, total_label(row, page.locale), label_type);
+        let cell = total.value(row);
+        page.cell(cell, cell.col, &value, value_type);
+    }
+
+    // Day header
+    for column in DayColumn::ALL {
+        let cell_type = match column {
+            DayColumn::Day | DayColumn::Bonus => CellType::Header,
+            DayColumn::Hours | DayColumn::PlannedHours | DayColumn::DayType => {
+                CellType::InputHeader
+            }
+        };
+        let cell = days.header(column);
+        page.cell(cell, cell.col, day_label(column, page.locale), cell_type);
+    }
+
+    // Days
+    for (index, (day, worked_hours)) in month.days().iter().zip(month.hours()).enumerate() {
+        let day_type = day.day_type();
+        let cell_type = day.cell_type();
+        let day_row = index as u32 + 1;
+        // Days out of employment have no hours and bonus
+        let worked_hours = match day.employed() {
+            true => *worked_hours,
+            false => 0.0,
+        };
+        let hours_text = match day.employed() {
+            true => hours(worked_hours),
+            false => NOT_EMPLOYED_MARK.to_string(),
+        };
+        let values = [
+            (
+                DayColumn::Day,
+                format!("{} {}", day.number(), day.weekday_short(page.locale)),
+            ),
+            (DayColumn::Hours, hours_text),
+            (
+                DayColumn::PlannedHours,
+                hours(day.planned_hours(timesheet.schedule())),
+            ),
+            (DayColumn::DayType, day_type.name().to_string()),
+        ];
+        for (column, value) in values {
+            let cell = days.cell(column, day_row);
+            page.cell(cell, cell.col, &value, cell_type);
+        }
+        let cell = days.cell(DayColumn::Bonus, day_row);
+        let bonus = MonthTotals::day_bonus(
+            totals.hour_rate,
+            worked_hours,
+            day_type,
+            timesheet.pay_rules(),
+        );
+        page.cell(cell, cell.col, &money(bonus), CellType::TotalBonus);
+        // Wider border at left of day block
+        let left = page.left(days.first_col());
+        let bottom = page.bottom(cell.row);
+        page.line(
+            (left, bottom),
+            (left, bottom + ROW_HEIGHT),
+            BorderWeight::Medium,
+        );
+    }
+    // Wider border at bottom of days block
+    let bottom = page.bottom(days.last_row(month.days().len() as u8));
+    page.line(
+        (page.left(days.first_col()), bottom),
+        (page.left(days.last_col() + 1), bottom),
+        BorderWeight::Medium,
+    );
+
+    // Colour legend
+    let cell = legend.title();
+    page.cell(cell, cell.col, LEGEND_TITLE, CellType::Header);
+    for (index, day_type) in DayType::ALL.iter().enumerate() {
+        let cell = legend.entry(index as u32);
+        page.cell(cell, cell.col, day_type.name(), day_type.cell_type());
+    }
+    let cell = legend.entry(DayType::ALL.len() as u32);
+    page.cell(cell, cell.col, INPUT_LEGEND, CellType::InputHeader);
+    if month.prorated() {
+        let cell = legend.entry(DayType::ALL.len() as u32 + 1);
+        page.cell(cell, cell.col, NOT_EMPLOYED, CellType::NotEmployed);
+    }
+}
+
+fn add_summary_cells(page: &PdfPage, timesheet: &Timesheet) {
+    let months = timesheet.months();
+    let last_col = SUMMARY_HEADERS.len() as u16 - 1;
+    page.cell(Cell::new(0, 0), 0, &timesheet.title(), CellType::Header);
+    page.cell(
+        Cell::new(0, 1),
+        last_col,
+        SUMMARY_SHEET_NAME,
+        CellType::Header,
+    );
+    for (col, header) in SUMMARY_HEADERS.into_iter().enumerate() {
+        page.cell(
+            Cell::new(1, col as u16),
+            col as u16,
+            header,
+            CellType::Header,
+        );
+    }
+
+    // Month rows goes after headers, totals goes after months
+    let mut year_totals = [0.0; 7];
+    for (index, month) in months.iter().enumerate() {
+        let row = 2 + index as u32;
+        let totals = month.totals();
+        let values = [
+            totals.norm_hours,
+            totals.worked_hours(),
+            totals.overtime_hours,
+            totals.weekend_hours,
+            totals.salary,
+            totals.bonus,
+            totals.payout,
+        ];
+        page.cell(
+            Cell::new(row, 0),
+            0,
+            month.title(page.locale),
+            CellType::Usual,
+        );
+        for (index, value) in values.into_iter().enumerate() {
+            year_totals[index] += value;
+            let col = index as u16 + 1;
+            let (text, cell_type) = summary_value(col, value, CellType::Usual);
+            page.cell(Cell::new(row, col), col, &text, cell_type);
+        }
+    }
+
+    // Year totals
+    let row = 2 + months.len() as u32;
+    page.cell(Cell::new(row, 0), 0, YEAR_TOTAL, CellType::Header);
+    for (index, value) in year_totals.into_iter().enumerate() {
+        let col = index as u16 + 1;
+        let (text, cell_type) = summary_value(col, value, CellType::Header);
+        page.cell(Cell::new(row, col), col, &text, cell_type);
+    }
+}
+
+// Salary and bonus columns are money, payout column is highlighted
+fn summary_value(col: u16, value: f64, hours_type: CellType) -> (String, CellType) {
+    match col {
+        5 | 6 => (money(value), CellType::TotalBonus),
+        7 => (money(value), CellType::TotalPayment),
+        _ => (hours(value), hours_type),
+    }
+}
+
+// Employee at left and page number at right
+fn add_footer(page: &PdfPage, settings: &Settings, number: usize, total_pages: usize) {
+    let baseline = MARGIN / 2.0;
+    page.layer.set_fill_color(color(0x000000));
+    if let Some(name) = &settings.print.employee_name {
+        page.layer.use_text(
+            name,
+            FOOTER_FONT_SIZE,
+            Mm(MARGIN),
+            Mm(baseline),
+            &page.fonts.regular,
+        );
+    }
+    let text = format!("Страница {} из {}", number, total_pages);
+    let width = page.fonts.text_width(&text, FOOTER_FONT_SIZE, false);
+    page.layer.use_text(
+        &text,
+        FOOTER_FONT_SIZE,
+        Mm(PAGE_WIDTH - MARGIN - width),
+        Mm(baseline),
+        &page.fonts.regular,
+    );
+}
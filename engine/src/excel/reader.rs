@@ -0,0 +1,289 @@
+use std::io::Cursor;
+
+use calamine::{Data, Range, Reader, Xlsx};
+use chrono::{Datelike, NaiveDate};
+
+use crate::excel::{
+    data::{
+        days::{Day, DayType, Days, NOT_EMPLOYED_MARK},
+        headers::{APP_INFO, day_label},
+        names,
+    },
+    error::{Error, Result as EResult},
+    layout::{Cell, DayColumn, MONTH_LAYOUT, TotalRow},
+    settings::{Locale, PayRules, Schedule},
+    timesheet::Timesheet,
+};
+
+// Month sheet read back from workbook
+struct MonthSheet {
+    days: Vec<Day>,
+    hours: Vec<f64>,
+    salary: u32,
+}
+
+pub(crate) fn read_timesheet(table: &[u8]) -> EResult<Timesheet> {
+    let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(table))?;
+    let pay_rules = read_pay_rules(workbook.defined_names());
+    let schedule = read_schedule(workbook.defined_names());
+    let mut months = Vec::new();
+    for sheet_name in workbook.sheet_names() {
+        let values = workbook.worksheet_range(&sheet_name)?;
+        // Summary and foreign sheets are skipped
+        let Some(locale) = month_sheet_locale(&values) else {
+            continue;
+        };
+        let formulas = workbook.worksheet_formula(&sheet_name)?;
+        months.push(read_month(&sheet_name, &values, &formulas, locale)?);
+    }
+    if months.is_empty() {
+        return Err(Error::InvalidTable(
+            "Cannot find month sheets of generated table!".into(),
+        ));
+    }
+    let days: Days = months.iter().flat_map(|m| m.days.clone()).collect();
+    let first_salary = months.first().unwrap().salary;
+    let mut timesheet = Timesheet::new(&days, first_salary, pay_rules, schedule);
+    for month in months {
+        let number = month.days.first().unwrap().date().month();
+        timesheet.set_salary(number, month.salary)?;
+        timesheet.set_hours(number, &month.hours)?;
+    }
+    Ok(timesheet)
+}
+
+// Multipliers are kept as workbook names, defaults are used if names are lost
+fn read_pay_rules(defined_names: &[(String, String)]) -> PayRules {
+    let rate = |day_type: DayType, default: f64| {
+        defined_names
+            .iter()
+            .find(|(name, _)| name == names::rate_name(&day_type))
+            .and_then(|(_, value)| value.trim_start_matches('=').parse().ok())
+            .unwrap_or(default)
+    };
+    let default = PayRules::default();
+    PayRules {
+        overtime: rate(DayType::Usual, default.overtime),
+        rest_day: rate(DayType::RestDay, default.rest_day),
+        holiday: rate(DayType::Holiday, default.holiday),
+    }
+}
+
+fn read_schedule(defined_names: &[(String, String)]) -> Schedule {
+    let work_day_hours = defined_names
+        .iter()
+        .find(|(name, _)| name == names::WORK_DAY_HOURS)
+        .and_then(|(_, value)| value.trim_start_matches('=').parse().ok());
+    match work_day_hours {
+        Some(work_day_hours) => Schedule { work_day_hours },
+        None => Schedule::default(),
+    }
+}
+
+// Generated month sheet has app info and day block headers at their places,
+// language of headers is the locale it was generated with
+fn month_sheet_locale(values: &Range<Data>) -> Option<Locale> {
+    let days = &MONTH_LAYOUT.days;
+    let (app_info, _) = MONTH_LAYOUT.header.app_info();
+    if text(values, app_info) != Some(APP_INFO) {
+        return None;
+    }
+    Locale::ALL.into_iter().find(|locale| {
+        DayColumn::ALL
+            .into_iter()
+            .all(|column| text(values, days.header(column)) == Some(day_label(column, *locale)))
+    })
+}
+
+fn read_month(
+    sheet_name: &str,
+    values: &Range<Data>,
+    formulas: &Range<String>,
+    locale: Locale,
+) -> EResult<MonthSheet> {
+    let header = &MONTH_LAYOUT.header;
+    let days = &MONTH_LAYOUT.days;
+    let year = match number(values, header.year()) {
+        Some(year) => year as i32,
+        None => {
+            return Err(Error::InvalidTable(
+                format!("Cannot find year at sheet {sheet_name}!").into(),
+            ));
+        }
+    };
+    // Month is taken from title, sheet can be renamed
+    let (title, _) = header.month();
+    let title = text(values, title).unwrap_or_default();
+    let Some(first_date) = (1..=12)
+        .filter_map(|month| NaiveDate::from_ymd_opt(year, month, 1))
+        .find(|date| Day::new(*date, DayType::Usual).month_name(locale) == title)
+    else {
+        return Err(Error::InvalidTable(
+            format!("Cannot find month at sheet {sheet_name}!").into(),
+        ));
+    };
+
+    // Day rows go until the end of month, days out of period are marked as not employed
+    let mut month_days = Vec::new();
+    let mut hours = Vec::new();
+    for day_row in 1..=31 {
+        let label = text(values, days.cell(DayColumn::Day, day_row));
+        let Some(date) = label
+            .and_then(|label| label.split_whitespace().next())
+            .and_then(|number| number.parse().ok())
+            .and_then(|number| first_date.with_day(number))
+        else {
+            break;
+        };
+        let type_name = text(values, days.cell(DayColumn::DayType, day_row));
+        let Some(day_type) = type_name.and_then(DayType::from_name) else {
+            return Err(Error::InvalidTable(
+                format!(
+                    "Unknown day type at {sheet_name}!{}",
+                    days.address(DayColumn::DayType, day_row)
+                )
+                .into(),
+            ));
+        };
+        let mut day = Day::new(date, day_type);
+        // Empty hours cell means nothing worked, mark means day out of employment
+        let cell = days.cell(DayColumn::Hours, day_row);
+        if text(values, cell) == Some(NOT_EMPLOYED_MARK) {
+            day.set_employed(false);
+        }
+        // Generated planned hours are a formula of day type, typed value is a correction
+        let planned = days.cell(DayColumn::PlannedHours, day_row);
+        let typed = formulas
+            .get_value((planned.row, planned.col as u32))
+            .is_none_or(|formula| formula.is_empty());
+        if day.employed() && typed {
+            let Some(value) = read_hours(values, planned) else {
+                return Err(Error::InvalidTable(
+                    format!(
+                        "Planned hours are not a number at {sheet_name}!{}",
+                        days.address(DayColumn::PlannedHours, day_row)
+                    )
+                    .into(),
+                ));
+            };
+            day.set_planned_hours(Some(value));
+        }
+        month_days.push(day);
+        match read_hours(values, cell) {
+            Some(value) => hours.push(value),
+            None => {
+                return Err(Error::InvalidTable(
+                    format!(
+                        "Hours are not a number at {sheet_name}!{}",
+                        days.address(DayColumn::Hours, day_row)
+                    )
+                    .into(),
+                ));
+            }
+        }
+    }
+    if month_days.is_empty() {
+        return Err(Error::InvalidTable(
+            format!("Cannot find days at sheet {sheet_name}!").into(),
+        ));
+    }
+    let salary = read_salary(values, formulas, MONTH_LAYOUT.total.value(TotalRow::Salary));
+    Ok(MonthSheet {
+        days: month_days,
+        hours,
+        salary,
+    })
+}
+
+// Generated salary is a formula like =50000 without computed value, until saved by Excel
+fn read_salary(values: &Range<Data>, formulas: &Range<String>, cell: Cell) -> u32 {
+    let value = number(values, cell).unwrap_or(0.0);
+    let salary = match formulas.get_value((cell.row, cell.col as u32)) {
+        Some(formula) if value == 0.0 => formula.trim_start_matches('=').parse().unwrap_or(0.0),
+        _ => value,
+    };
+    salary.round().max(0.0) as u32
+}
+
+// Empty cell and mark of day out of employment are zero hours
+fn read_hours(values: &Range<Data>, cell: Cell) -> Option<f64> {
+    match values.get_value((cell.row, cell.col as u32)) {
+        None | Some(Data::Empty) => Some(0.0),
+        Some(Data::String(text)) if text == NOT_EMPLOYED_MARK => Some(0.0),
+        _ => number(values, cell),
+    }
+}
+
+fn text(values: &Range<Data>, cell: Cell) -> Option<&str> {
+    match values.get_value((cell.row, cell.col as u32)) {
+        Some(Data::String(text)) => Some(text.as_str()),
+        _ => None,
+    }
+}
+
+// Numbers typed as text with decimal comma are accepted too
+fn number(values: &Range<Data>, cell: Cell) -> Option<f64> {
+    match values.get_value((cell.row, cell.col as u32))? {
+        Data::Float(value) => Some(*value),
+        Data::Int(value) => Some(*value as f64),
+        Data::String(text) => text.trim().replace(',', ".").parse().ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::excel::{
+        render::{Renderer, XlsxRenderer},
+        settings::{Period, Settings},
+    };
+
+    #[test]
+    fn generated_table_is_read_back() {
+        let mut days = Days::new(&Period::month(2026, 3).unwrap(), &HashSet::new());
+        // Shortened day before holiday typed by user
+        days[5].set_planned_hours(Some(7.0));
+        let mut timesheet = Timesheet::new(&days, 60000, PayRules::default(), Schedule::default());
+        let mut hours = vec![0.0; days.len()];
+        hours[2] = 2.0;
+        hours[6] = 5.0;
+        timesheet.set_hours(3, &hours).unwrap();
+        let table = XlsxRenderer
+            .render(&timesheet, &Settings::default())
+            .unwrap();
+
+        let read = read_timesheet(&table).unwrap();
+        let (month, read_month) = (&timesheet.months()[0], &read.months()[0]);
+        assert_eq!(read_month.hours(), month.hours());
+        assert_eq!(read_month.salary(), 60000);
+        assert_eq!(read_month.days()[5].corrected_planned_hours(), Some(7.0));
+        assert_eq!(read_month.days()[4].corrected_planned_hours(), None);
+        assert_eq!(read_month.totals(), month.totals());
+        // 22 working days of March 2026, one of them shortened
+        assert_eq!(month.totals().norm_hours, 21.0 * 8.0 + 7.0);
+    }
+
+    #[test]
+    fn cut_period_table_is_read_back() {
+        let period = Period::new(
+            NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 1, 20).unwrap(),
+        )
+        .unwrap();
+        let days = Days::new(&period, &HashSet::new());
+        let timesheet = Timesheet::new(&days, 100000, PayRules::default(), Schedule::default());
+        let table = XlsxRenderer
+            .render(&timesheet, &Settings::default())
+            .unwrap();
+
+        let read = read_timesheet(&table).unwrap();
+        assert_eq!(read.title(), "2025-2026");
+        for (month, read_month) in timesheet.months().iter().zip(read.months()) {
+            assert_eq!(read_month.days().len(), month.days().len());
+            assert_eq!(read_month.totals(), month.totals());
+        }
+    }
+}
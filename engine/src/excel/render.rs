@@ -0,0 +1,47 @@
+use crate::excel::{
+    error::Result as EResult,
+    settings::{OutputFormat, Settings},
+    timesheet::Timesheet,
+};
+
+/// Output format of timesheet, gets pay rules from timesheet and look from settings
+pub trait Renderer {
+    fn render(&self, timesheet: &Timesheet, settings: &Settings) -> EResult<Vec<u8>>;
+}
+
+/// Excel workbook with formulas
+pub struct XlsxRenderer;
+
+/// OpenDocument spreadsheet with formulas
+pub struct OdsRenderer;
+
+/// Read-only document with computed totals, errors without `pdf` feature
+pub struct PdfRenderer;
+
+/// Computed timesheet as JSON for other services
+pub struct JsonRenderer;
+
+/// Unified form T-13 for accounting
+pub struct T13Renderer;
+
+impl OutputFormat {
+    pub fn renderer(&self) -> &'static dyn Renderer {
+        match self {
+            OutputFormat::Xlsx => &XlsxRenderer,
+            OutputFormat::Ods => &OdsRenderer,
+            OutputFormat::Pdf => &PdfRenderer,
+            OutputFormat::Json => &JsonRenderer,
+            OutputFormat::T13 => &T13Renderer,
+        }
+    }
+}
+
+// Format is still known, so callers get a clear error instead of unknown format
+#[cfg(not(feature = "pdf"))]
+impl Renderer for PdfRenderer {
+    fn render(&self, _timesheet: &Timesheet, _settings: &Settings) -> EResult<Vec<u8>> {
+        Err(crate::excel::error::Error::InvalidOptions(
+            "PDF output needs engine pdf feature!".to_string(),
+        ))
+    }
+}
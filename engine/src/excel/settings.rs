@@ -0,0 +1,394 @@
+use std::{path::PathBuf, str::FromStr};
+
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::excel::{
+    data::days,
+    error::{Error, Result as EResult},
+    theme::Theme,
+};
+
+/// Pay multipliers applied to hours worked at different day types
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PayRules {
+    /// Overtime hours at usual working day
+    pub overtime: f64,
+    /// Hours worked at day off of calendar that is not a public holiday
+    pub rest_day: f64,
+    /// Hours worked at public holiday, at weekend too
+    pub holiday: f64,
+}
+
+impl Default for PayRules {
+    fn default() -> Self {
+        Self {
+            overtime: 2.0,
+            rest_day: 2.0,
+            holiday: 2.0,
+        }
+    }
+}
+
+impl PayRules {
+    // Multipliers are written as workbook names, so they must be plain numbers
+    pub(crate) fn validate(&self) -> EResult<()> {
+        let multipliers = [
+            ("overtime", self.overtime),
+            ("rest day", self.rest_day),
+            ("holiday", self.holiday),
+        ];
+        for (name, multiplier) in multipliers {
+            if !multiplier.is_finite() || multiplier < 0.0 {
+                return Err(Error::InvalidOptions(format!(
+                    "Pay multiplier of {name} must be a number from 0, got {multiplier}!"
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Working time of employee
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Schedule {
+    /// Planned hours of working day, less than 8 for part-time
+    pub work_day_hours: f64,
+}
+
+impl Default for Schedule {
+    fn default() -> Self {
+        Self {
+            work_day_hours: days::WORK_DAY_HOURS as f64,
+        }
+    }
+}
+
+impl Schedule {
+    pub(crate) fn validate(&self) -> EResult<()> {
+        let hours = self.work_day_hours;
+        if hours.is_nan() || hours <= 0.0 || hours > 24.0 {
+            return Err(Error::InvalidOptions(format!(
+                "Work day hours must be more than 0 and up to 24, got {hours}!"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Worksheet protection, only input cells stay editable
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Protection {
+    /// Password to unprotect worksheets, protected without password if none
+    pub password: Option<String>,
+}
+
+/// Page setup for printing month worksheets at A4 portrait
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintSetup {
+    /// Employee name at page footer
+    pub employee_name: Option<String>,
+    /// Fit worksheet to one page wide
+    pub fit_to_width: bool,
+    /// Repeat day header row at every page
+    pub repeat_header: bool,
+}
+
+impl Default for PrintSetup {
+    fn default() -> Self {
+        Self {
+            employee_name: None,
+            fit_to_width: true,
+            repeat_header: true,
+        }
+    }
+}
+
+/// Employer and employee fields at header of unified form T-13, left blank if none
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FormFields {
+    pub organization: Option<String>,
+    /// Organization code by OKPO
+    pub okpo: Option<String>,
+    /// Structural department
+    pub department: Option<String>,
+    pub document_number: Option<String>,
+    pub employee_name: Option<String>,
+    pub position: Option<String>,
+    pub personnel_number: Option<String>,
+}
+
+/// Inclusive range of counted days, days of first and last months out of it are greyed out
+/// and salary of those months is prorated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Period {
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl Period {
+    /// Range up to twelve months, a sheet is made for every month it overlaps
+    pub fn new(start: NaiveDate, end: NaiveDate) -> EResult<Self> {
+        if start > end {
+            return Err(Error::InvalidOptions(
+                "Period starts after its end!".to_string(),
+            ));
+        }
+        // Month sheets are named without year, so every month appears once
+        let months = (end.year() - start.year()) * 12 + end.month() as i32 - start.month() as i32;
+        if months >= 12 {
+            return Err(Error::InvalidOptions(
+                "Period is longer than twelve months!".to_string(),
+            ));
+        }
+        Ok(Self { start, end })
+    }
+
+    pub fn year(year: i32) -> EResult<Self> {
+        Self::months(year, 1, 12)
+    }
+
+    /// Quarter from 1 to 4
+    pub fn quarter(year: i32, quarter: u32) -> EResult<Self> {
+        if !(1..=4).contains(&quarter) {
+            return Err(Error::InvalidOptions(format!(
+                "Cannot find quarter {quarter}!"
+            )));
+        }
+        Self::months(year, quarter * 3 - 2, 3)
+    }
+
+    /// Month from 1 to 12
+    pub fn month(year: i32, month: u32) -> EResult<Self> {
+        Self::months(year, month, 1)
+    }
+
+    fn months(year: i32, first_month: u32, count: u32) -> EResult<Self> {
+        let Some(start) = NaiveDate::from_ymd_opt(year, first_month, 1) else {
+            return Err(Error::InvalidOptions(format!(
+                "Cannot find month {first_month} of {year}!"
+            )));
+        };
+        let end = start + Months::new(count) - chrono::Days::new(1);
+        Self::new(start, end)
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+}
+
+/// Employment dates, days out of them are greyed out and salary is prorated
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Employment {
+    /// First working day, employed since period start if none
+    pub hired: Option<NaiveDate>,
+    /// Last working day, employed until period end if none
+    pub terminated: Option<NaiveDate>,
+}
+
+impl Employment {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.hired.is_none_or(|hired| hired <= date)
+            && self.terminated.is_none_or(|terminated| date <= terminated)
+    }
+}
+
+/// File format of generated table
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OutputFormat {
+    /// Excel workbook
+    #[default]
+    Xlsx,
+    /// OpenDocument spreadsheet for LibreOffice, worksheet protection and chart are not supported
+    Ods,
+    /// Read-only document with totals computed at generation, rendered with `pdf` feature only
+    Pdf,
+    /// Computed timesheet, schema version is written at `schema_version` field
+    Json,
+    /// Unified form T-13 workbook with day codes, a sheet per month
+    T13,
+}
+
+impl OutputFormat {
+    /// File extension without dot
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Xlsx => "xlsx",
+            OutputFormat::Ods => "ods",
+            OutputFormat::Pdf => "pdf",
+            OutputFormat::Json => "json",
+            OutputFormat::T13 => "xlsx",
+        }
+    }
+
+    /// Media type for downloads and HTTP responses
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            OutputFormat::Xlsx | OutputFormat::T13 => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+            OutputFormat::Ods => "application/vnd.oasis.opendocument.spreadsheet",
+            OutputFormat::Pdf => "application/pdf",
+            OutputFormat::Json => "application/json",
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(name: &str) -> EResult<Self> {
+        match name.to_lowercase().as_str() {
+            "xlsx" => Ok(OutputFormat::Xlsx),
+            "ods" => Ok(OutputFormat::Ods),
+            "pdf" => Ok(OutputFormat::Pdf),
+            "json" => Ok(OutputFormat::Json),
+            "t13" => Ok(OutputFormat::T13),
+            _ => Err(Error::InvalidOptions(format!(
+                "Unknown output format {name}!"
+            ))),
+        }
+    }
+}
+
+// Public calendar with holidays array in "year-month-day" format
+#[cfg(feature = "http")]
+pub(crate) const DEFAULT_CALENDAR_URL: &str = "https://raw.githubusercontent.com/d10xa/holidays-calendar/refs/heads/master/json/calendar.json";
+
+/// Where holiday calendar is taken from, JSON with `holidays` array of every day off.
+/// Weekends missing in it are working days, public holiday dates are paid as holidays
+#[derive(Debug, Clone, PartialEq)]
+pub enum CalendarSource {
+    /// Downloaded with `http` feature, errors as invalid options without it
+    Url(String),
+    File(PathBuf),
+    /// Holidays supplied by caller, nothing is read, empty ones are an error
+    Holidays(Vec<NaiveDate>),
+}
+
+// Without networking there is no calendar to take, so empty holidays ask caller to supply it
+impl Default for CalendarSource {
+    #[cfg(feature = "http")]
+    fn default() -> Self {
+        CalendarSource::Url(DEFAULT_CALENDAR_URL.to_string())
+    }
+
+    #[cfg(not(feature = "http"))]
+    fn default() -> Self {
+        CalendarSource::Holidays(Vec::new())
+    }
+}
+
+/// Language of table texts
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Locale {
+    /// Day types at formulas are russian too, so only it is available for now
+    #[default]
+    Ru,
+}
+
+impl Locale {
+    pub(crate) const ALL: [Locale; 1] = [Locale::Ru];
+}
+
+impl FromStr for Locale {
+    type Err = Error;
+
+    fn from_str(code: &str) -> EResult<Self> {
+        match code.to_lowercase().as_str() {
+            "ru" | "ru-ru" => Ok(Locale::Ru),
+            _ => Err(Error::InvalidOptions(format!("Unsupported locale {code}!"))),
+        }
+    }
+}
+
+/// Table generation settings
+#[derive(Debug, Clone)]
+pub struct Settings {
+    pub pay_rules: PayRules,
+    pub schedule: Schedule,
+    /// Worksheets stay unprotected if none
+    pub protection: Option<Protection>,
+    pub theme: Theme,
+    pub print: PrintSetup,
+    pub format: OutputFormat,
+    pub form: FormFields,
+    /// Last year of holiday calendar if none
+    pub period: Option<Period>,
+    /// Months out of employment are not generated
+    pub employment: Employment,
+    pub calendar: CalendarSource,
+    /// Labels, month and weekday names
+    pub locale: Locale,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            pay_rules: PayRules::default(),
+            schedule: Schedule::default(),
+            protection: Some(Protection::default()),
+            theme: Theme::default(),
+            print: PrintSetup::default(),
+            format: OutputFormat::default(),
+            form: FormFields::default(),
+            period: None,
+            employment: Employment::default(),
+            calendar: CalendarSource::default(),
+            locale: Locale::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn period_is_checked() {
+        assert!(Period::new(date(2026, 3, 2), date(2026, 3, 1)).is_err());
+        assert!(Period::new(date(2025, 1, 1), date(2026, 1, 1)).is_err());
+        let period = Period::new(date(2025, 11, 17), date(2026, 10, 31)).unwrap();
+        assert_eq!(period.start(), date(2025, 11, 17));
+        assert_eq!(period.end(), date(2026, 10, 31));
+    }
+
+    #[test]
+    fn period_months_are_whole() {
+        assert_eq!(
+            Period::year(2026).unwrap(),
+            Period::new(date(2026, 1, 1), date(2026, 12, 31)).unwrap()
+        );
+        assert_eq!(
+            Period::quarter(2026, 2).unwrap(),
+            Period::new(date(2026, 4, 1), date(2026, 6, 30)).unwrap()
+        );
+        assert_eq!(Period::month(2028, 2).unwrap().end(), date(2028, 2, 29));
+        assert!(Period::quarter(2026, 5).is_err());
+        assert!(Period::month(2026, 13).is_err());
+    }
+
+    #[test]
+    fn pay_rules_and_schedule_are_checked() {
+        assert!(PayRules::default().validate().is_ok());
+        for multiplier in [-1.0, f64::NAN, f64::INFINITY] {
+            let rules = PayRules {
+                rest_day: multiplier,
+                ..Default::default()
+            };
+            assert!(rules.validate().is_err());
+        }
+        assert!(Schedule::default().validate().is_ok());
+        for work_day_hours in [0.0, 25.0, f64::NAN] {
+            assert!(Schedule { work_day_hours }.validate().is_err());
+        }
+    }
+}
@@ -1,69 +1,97 @@
 use rust_xlsxwriter::{Color, Format, FormatBorder};
 
+use crate::excel::theme::Theme;
+
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum DataType {
     Money,     // Rounded to 2 decimal places and formatted as currency
     UsualText, // Nothing to do
 }
 
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum CellType {
     Usual,        // For usual cells - white background dotted border and bold font
-    Weekend,      // For weekend day cells - white red background dotted border and bold font
-    Earn,         // For earn cells - green background dotted border and bold font
-    Header,       // For header cells - pink background and solid border normal font
+    RestDay,      // For rest day cells - rest day background dotted border and bold font
+    Holiday,      // For holiday cells - holiday background dotted border and bold font
+    Vacation,     // For vacation cells - vacation background dotted border and bold font
+    SickLeave,    // For sick leave cells - sick leave background dotted border and bold font
+    NotEmployed,  // For days out of employment - grey background dotted border and normal font
+    Header,       // For header cells - header background and solid border normal font
     TotalBonus,   // For total bonus cells - white background solid border and bold font
-    TotalPayment, // For earn cells - green background solid border and bold font
-    InputHeader,  // For input header cells - orange background solid border and normal font
-    MonthWinter,  // For month winter cells - blue background solid border and normal font
-    MonthSummer,  // For month summer cells - yellow background solid border and normal font
-    MonthAutumn,  // For month autumn cells - orange background solid border and normal font
-    MonthSpring,  // For month spring cells - green background solid border and normal font
+    TotalPayment, // For earn cells - payment background solid border and bold font
+    InputHeader,  // For input header cells - input background solid border normal font
+    MonthWinter,  // For month winter cells - winter background solid border and normal font
+    MonthSummer,  // For month summer cells - summer background solid border and normal font
+    MonthAutumn,  // For month autumn cells - autumn background solid border and normal font
+    MonthSpring,  // For month spring cells - spring background solid border and normal font
 }
 
-pub(crate) fn cell_style(data_type: DataType, cell_type: CellType) -> Format {
-    let mut format = match cell_type {
-        CellType::Usual => Format::new().set_border(FormatBorder::Dotted).set_bold(),
-
-        CellType::Weekend => Format::new()
-            .set_border(FormatBorder::Dotted)
-            .set_bold()
-            .set_background_color(Color::RGB(0xF8B9B8)),
-
-        CellType::Earn => Format::new()
-            .set_border(FormatBorder::Dotted)
-            .set_bold()
-            .set_background_color(Color::RGB(0xB2E19E)),
-
-        CellType::Header => Format::new()
-            .set_border(FormatBorder::Medium)
-            .set_background_color(Color::RGB(0xEDCAE9)),
+// Background colour from theme, cells without background are white
+pub(crate) fn background_rgb(theme: &Theme, cell_type: &CellType) -> Option<u32> {
+    let color = match cell_type {
+        CellType::Usual | CellType::TotalBonus => return None,
+        CellType::RestDay => theme.rest_day,
+        CellType::Holiday => theme.holiday,
+        CellType::Vacation => theme.vacation,
+        CellType::SickLeave => theme.sick_leave,
+        CellType::NotEmployed => theme.not_employed,
+        CellType::Header => theme.header,
+        CellType::TotalPayment => theme.payment,
+        CellType::InputHeader => theme.input,
+        CellType::MonthWinter => theme.winter,
+        CellType::MonthSpring => theme.spring,
+        CellType::MonthSummer => theme.summer,
+        CellType::MonthAutumn => theme.autumn,
+    };
+    Some(color)
+}
 
-        CellType::TotalBonus => Format::new().set_border(FormatBorder::Medium).set_bold(),
+fn background_color(theme: &Theme, cell_type: &CellType) -> Option<Color> {
+    background_rgb(theme, cell_type).map(Color::RGB)
+}
 
-        CellType::TotalPayment => Format::new()
-            .set_border(FormatBorder::Medium)
-            .set_bold()
-            .set_background_color(Color::RGB(0xB2E19E)),
+// Day cells have dotted borders, other cells have medium borders
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BorderWeight {
+    Dotted,
+    Medium,
+}
 
-        CellType::InputHeader => Format::new()
-            .set_border(FormatBorder::Medium)
-            .set_background_color(Color::RGB(0xF0C2A7)),
+// Border and bold font of cell type, same for every output format
+pub(crate) fn cell_outline(cell_type: &CellType) -> (BorderWeight, bool) {
+    match cell_type {
+        CellType::Usual
+        | CellType::RestDay
+        | CellType::Holiday
+        | CellType::Vacation
+        | CellType::SickLeave => (BorderWeight::Dotted, true),
 
-        CellType::MonthWinter => Format::new()
-            .set_border(FormatBorder::Medium)
-            .set_background_color(Color::RGB(0xC6E8F4)),
+        CellType::NotEmployed => (BorderWeight::Dotted, false),
 
-        CellType::MonthSpring => Format::new()
-            .set_border(FormatBorder::Medium)
-            .set_background_color(Color::RGB(0xB2E19E)),
+        CellType::TotalBonus | CellType::TotalPayment => (BorderWeight::Medium, true),
 
-        CellType::MonthSummer => Format::new()
-            .set_border(FormatBorder::Medium)
-            .set_background_color(Color::RGB(0xFFE699)),
+        CellType::Header
+        | CellType::InputHeader
+        | CellType::MonthWinter
+        | CellType::MonthSpring
+        | CellType::MonthSummer
+        | CellType::MonthAutumn => (BorderWeight::Medium, false),
+    }
+}
 
-        CellType::MonthAutumn => Format::new()
-            .set_border(FormatBorder::Medium)
-            .set_background_color(Color::RGB(0xF0C1A7)),
+pub(crate) fn cell_style(theme: &Theme, data_type: DataType, cell_type: CellType) -> Format {
+    let (border, bold) = cell_outline(&cell_type);
+    let mut format = match border {
+        BorderWeight::Dotted => Format::new().set_border(FormatBorder::Dotted),
+        BorderWeight::Medium => Format::new().set_border(FormatBorder::Medium),
     };
+    if bold {
+        format = format.set_bold();
+    }
+
+    if let Some(color) = background_color(theme, &cell_type) {
+        format = format.set_background_color(color);
+    }
 
     format = match data_type {
         DataType::Money => format.set_num_format("#,##0.00\" ₽\""),
@@ -72,3 +100,9 @@ pub(crate) fn cell_style(data_type: DataType, cell_type: CellType) -> Format {
 
     format.set_align(rust_xlsxwriter::FormatAlign::Center)
 }
+
+// Conditional formatting changes only background of day cells
+pub(crate) fn conditional_style(theme: &Theme, cell_type: CellType) -> Format {
+    let color = background_color(theme, &cell_type).unwrap_or(Color::White);
+    Format::new().set_background_color(color)
+}
@@ -0,0 +1,843 @@
+use chrono::{Datelike, Months};
+use rust_xlsxwriter::{
+    ColNum, Format, FormatAlign, FormatBorder, RowNum, workbook::Workbook, worksheet::Worksheet,
+};
+
+use crate::excel::{
+    error::Result as EResult,
+    render::{Renderer, T13Renderer},
+    settings::{FormFields, Schedule, Settings},
+    timesheet::{Day, DayType, Month, Timesheet},
+};
+
+// Columns of unified form, numbers at comments are form column numbers
+const NUMBER_COL: ColNum = 0; // 1
+const NAME_COL: ColNum = 1; // 2
+const PERSONNEL_COL: ColNum = 2; // 3
+const FIRST_DAY_COL: ColNum = 3; // 4, 15 days and a crossed cell per half
+const HALF_COL: ColNum = 19; // 5
+const MONTH_DAYS_COL: ColNum = 20; // 6
+const MONTH_HOURS_COL: ColNum = 21; // 6
+const PAY_CODE_COL: ColNum = 22; // 7
+const ACCOUNT_COL: ColNum = 23; // 8
+const PAY_DAYS_COL: ColNum = 24; // 9
+const ABSENCE_COLS: [ColNum; 2] = [25, 27]; // 10 and 12, days at 11 and 13
+const LAST_COL: ColNum = 28;
+
+// Rows of form from top to bottom
+const TABLE_HEADER_ROW: RowNum = 15;
+const EMPLOYEE_ROW: RowNum = 19;
+const SIGNATURES_ROW: RowNum = 24;
+
+// First half of month is days 1-15
+const HALF_DAYS: u32 = 15;
+
+impl Renderer for T13Renderer {
+    fn render(&self, timesheet: &Timesheet, settings: &Settings) -> EResult<Vec<u8>> {
+        let mut table = Workbook::new();
+        let formats = Formats::new();
+        for month in timesheet.months() {
+            let worksheet = table.add_worksheet();
+            worksheet.set_name(month.title(settings.locale))?;
+            add_form_header(worksheet, &formats, month, &settings.form)?;
+            add_table_header(worksheet, &formats)?;
+            add_employee_rows(
+                worksheet,
+                &formats,
+                month,
+                timesheet.schedule(),
+                &settings.form,
+            )?;
+            add_signatures(worksheet, &formats)?;
+            add_code_legend(worksheet, &formats)?;
+            setup_sheet(worksheet)?;
+        }
+        let buf = table.save_to_buffer()?;
+        Ok(buf)
+    }
+}
+
+// Day codes of unified form
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DayCode {
+    Attendance,
+    Night,
+    DayOff,
+    WeekendWork,
+    Vacation,
+    SickLeave,
+    Overtime,
+}
+
+impl DayCode {
+    const ALL: [DayCode; 7] = [
+        DayCode::Attendance,
+        DayCode::Night,
+        DayCode::DayOff,
+        DayCode::WeekendWork,
+        DayCode::Vacation,
+        DayCode::SickLeave,
+        DayCode::Overtime,
+    ];
+
+    // Absences are listed at form columns 10-13 in this order
+    const ABSENCES: [DayCode; 3] = [DayCode::DayOff, DayCode::Vacation, DayCode::SickLeave];
+
+    fn code(&self) -> &'static str {
+        match self {
+            DayCode::Attendance => "Я",
+            DayCode::Night => "Н",
+            DayCode::DayOff => "В",
+            DayCode::WeekendWork => "РВ",
+            DayCode::Vacation => "ОТ",
+            DayCode::SickLeave => "Б",
+            DayCode::Overtime => "С",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            DayCode::Attendance => "Продолжительность работы в дневное время",
+            DayCode::Night => "Продолжительность работы в ночное время",
+            DayCode::DayOff => "Выходные дни и нерабочие праздничные дни",
+            DayCode::WeekendWork => {
+                "Продолжительность работы в выходные и нерабочие праздничные дни"
+            }
+            DayCode::Vacation => "Ежегодный основной оплачиваемый отпуск",
+            DayCode::SickLeave => "Временная нетрудоспособность с назначением пособия",
+            DayCode::Overtime => "Продолжительность сверхурочной работы",
+        }
+    }
+}
+
+// Codes and hours of day cell, several codes are joined with slash
+struct DayMark {
+    codes: Vec<DayCode>,
+    hours: Vec<f64>,
+}
+
+impl DayMark {
+    fn new(day: &Day, worked: f64, schedule: &Schedule) -> Self {
+        let (codes, hours) = match day.day_type() {
+            DayType::Usual => {
+                let planned = day.planned_hours(schedule);
+                if worked > 0.0 {
+                    (
+                        vec![DayCode::Attendance, DayCode::Overtime],
+                        vec![planned, worked],
+                    )
+                } else {
+                    (vec![DayCode::Attendance], vec![planned])
+                }
+            }
+            DayType::RestDay | DayType::Holiday if worked > 0.0 => {
+                (vec![DayCode::WeekendWork], vec![worked])
+            }
+            DayType::RestDay | DayType::Holiday => (vec![DayCode::DayOff], vec![]),
+            DayType::Vacation => (vec![DayCode::Vacation], vec![]),
+            DayType::SickLeave => (vec![DayCode::SickLeave], vec![]),
+        };
+        Self { codes, hours }
+    }
+
+    fn code_text(&self) -> String {
+        self.codes
+            .iter()
+            .map(DayCode::code)
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn hours_text(&self) -> String {
+        self.hours
+            .iter()
+            .map(|h| hours(*h))
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn worked_hours(&self) -> f64 {
+        self.hours.iter().sum()
+    }
+
+    fn absence(&self) -> Option<DayCode> {
+        self.codes
+            .first()
+            .copied()
+            .filter(|code| DayCode::ABSENCES.contains(code))
+    }
+}
+
+struct Formats {
+    cell: Format,
+    bold: Format,
+    title: Format,
+    field: Format,
+    caption: Format,
+    label: Format,
+    code: Format,
+}
+
+impl Formats {
+    fn new() -> Self {
+        let text = Format::new()
+            .set_font_size(8)
+            .set_align(FormatAlign::Center)
+            .set_align(FormatAlign::VerticalCenter)
+            .set_text_wrap();
+        Self {
+            cell: text.clone().set_border(FormatBorder::Thin),
+            bold: text.clone().set_bold(),
+            title: text.clone().set_font_size(11).set_bold(),
+            field: text
+                .clone()
+                .set_font_size(9)
+                .set_border_bottom(FormatBorder::Thin),
+            caption: text.clone().set_font_size(6).set_align(FormatAlign::Top),
+            label: text.clone().set_align(FormatAlign::Right),
+            code: text
+                .set_font_size(9)
+                .set_border(FormatBorder::Medium)
+                .set_bold(),
+        }
+    }
+}
+
+fn add_form_header(
+    worksheet: &mut Worksheet,
+    formats: &Formats,
+    month: &Month,
+    form: &FormFields,
+) -> EResult<()> {
+    let code_cols = (ABSENCE_COLS[1], LAST_COL);
+    let label_cols = (PAY_CODE_COL, PAY_DAYS_COL + 1);
+    // Form approval at top right
+    write_range(
+        worksheet,
+        (0, 0),
+        (0, LAST_COL),
+        "Унифицированная форма № Т-13",
+        &formats.label,
+    )?;
+    write_range(
+        worksheet,
+        (1, 0),
+        (1, LAST_COL),
+        "Утверждена постановлением Госкомстата России от 05.01.2004 № 1",
+        &formats.label,
+    )?;
+    // Codes box
+    write_range(
+        worksheet,
+        (3, code_cols.0),
+        (3, code_cols.1),
+        "Код",
+        &formats.cell,
+    )?;
+    write_range(
+        worksheet,
+        (4, label_cols.0),
+        (4, label_cols.1),
+        "Форма по ОКУД",
+        &formats.label,
+    )?;
+    write_range(
+        worksheet,
+        (4, code_cols.0),
+        (4, code_cols.1),
+        "0301008",
+        &formats.code,
+    )?;
+    write_range(
+        worksheet,
+        (5, label_cols.0),
+        (5, label_cols.1),
+        "по ОКПО",
+        &formats.label,
+    )?;
+    write_range(
+        worksheet,
+        (5, code_cols.0),
+        (5, code_cols.1),
+        field(&form.okpo),
+        &formats.code,
+    )?;
+    // Employer
+    write_range(
+        worksheet,
+        (5, 0),
+        (5, MONTH_HOURS_COL),
+        field(&form.organization),
+        &formats.field,
+    )?;
+    write_range(
+        worksheet,
+        (6, 0),
+        (6, MONTH_HOURS_COL),
+        "(наименование организации)",
+        &formats.caption,
+    )?;
+    write_range(
+        worksheet,
+        (7, 0),
+        (7, MONTH_HOURS_COL),
+        field(&form.department),
+        &formats.field,
+    )?;
+    write_range(
+        worksheet,
+        (8, 0),
+        (8, MONTH_HOURS_COL),
+        "(структурное подразделение)",
+        &formats.caption,
+    )?;
+
+    // Document number, date and period
+    let first_date = month.first_day().date();
+    let last_date = month.days().last().unwrap().date();
+    let date = |date: chrono::NaiveDate| date.format("%d.%m.%Y").to_string();
+    let number_cols = (14, 16);
+    let date_cols = (17, 19);
+    let (from_cols, to_cols) = ((20, 21), (22, 23));
+    write_range(
+        worksheet,
+        (10, number_cols.0),
+        (11, number_cols.1),
+        "Номер документа",
+        &formats.cell,
+    )?;
+    write_range(
+        worksheet,
+        (10, date_cols.0),
+        (11, date_cols.1),
+        "Дата составления",
+        &formats.cell,
+    )?;
+    write_range(
+        worksheet,
+        (10, from_cols.0),
+        (10, to_cols.1),
+        "Отчетный период",
+        &formats.cell,
+    )?;
+    write_range(
+        worksheet,
+        (11, from_cols.0),
+        (11, from_cols.1),
+        "с",
+        &formats.cell,
+    )?;
+    write_range(
+        worksheet,
+        (11, to_cols.0),
+        (11, to_cols.1),
+        "по",
+        &formats.cell,
+    )?;
+    write_range(
+        worksheet,
+        (12, 6),
+        (12, 13),
+        "ТАБЕЛЬ",
+        &formats.title.clone().set_align(FormatAlign::Right),
+    )?;
+    write_range(
+        worksheet,
+        (12, number_cols.0),
+        (12, number_cols.1),
+        field(&form.document_number),
+        &formats.code,
+    )?;
+    // Timesheet is made up at last day of month
+    write_range(
+        worksheet,
+        (12, date_cols.0),
+        (12, date_cols.1),
+        &date(last_date),
+        &formats.code,
+    )?;
+    write_range(
+        worksheet,
+        (12, from_cols.0),
+        (12, from_cols.1),
+        &date(first_date),
+        &formats.code,
+    )?;
+    write_range(
+        worksheet,
+        (12, to_cols.0),
+        (12, to_cols.1),
+        &date(last_date),
+        &formats.code,
+    )?;
+    write_range(
+        worksheet,
+        (13, 6),
+        (13, 13),
+        "учета рабочего времени",
+        &formats.bold.clone().set_align(FormatAlign::Right),
+    )?;
+    Ok(())
+}
+
+fn add_table_header(worksheet: &mut Worksheet, formats: &Formats) -> EResult<()> {
+    let row = TABLE_HEADER_ROW;
+    let last_day_col = FIRST_DAY_COL + HALF_DAYS as ColNum;
+    let format = &formats.cell;
+    // Columns spanning all header rows
+    let full_height = [
+        (NUMBER_COL, "Номер по порядку"),
+        (
+            NAME_COL,
+            "Фамилия, инициалы, должность (специальность, профессия)",
+        ),
+        (PERSONNEL_COL, "Табельный номер"),
+    ];
+    for (col, text) in full_height {
+        write_range(worksheet, (row, col), (row + 2, col), text, format)?;
+    }
+    write_range(
+        worksheet,
+        (row, FIRST_DAY_COL),
+        (row, last_day_col),
+        "Отметки о явках и неявках на работу по числам месяца",
+        format,
+    )?;
+    write_range(
+        worksheet,
+        (row, HALF_COL),
+        (row, MONTH_HOURS_COL),
+        "Отработано за",
+        format,
+    )?;
+    write_range(
+        worksheet,
+        (row, PAY_CODE_COL),
+        (row, PAY_DAYS_COL),
+        "Данные для начисления заработной платы по видам и направлениям затрат",
+        format,
+    )?;
+    write_range(
+        worksheet,
+        (row, ABSENCE_COLS[0]),
+        (row, LAST_COL),
+        "Неявки по причинам",
+        format,
+    )?;
+
+    // Day numbers of both halves, first half ends with crossed cell
+    for index in 0..=HALF_DAYS {
+        let col = FIRST_DAY_COL + index as ColNum;
+        let first_half = match index {
+            HALF_DAYS => "X".to_string(),
+            _ => (index + 1).to_string(),
+        };
+        worksheet.write_with_format(row + 1, col, first_half, format)?;
+        worksheet.write_with_format(row + 2, col, (index + HALF_DAYS + 1).to_string(), format)?;
+    }
+    write_range(
+        worksheet,
+        (row + 1, HALF_COL),
+        (row + 2, HALF_COL),
+        "половину месяца (I, II)",
+        format,
+    )?;
+    write_range(
+        worksheet,
+        (row + 1, MONTH_DAYS_COL),
+        (row + 1, MONTH_HOURS_COL),
+        "месяц",
+        format,
+    )?;
+    worksheet.write_with_format(row + 2, MONTH_DAYS_COL, "дни", format)?;
+    worksheet.write_with_format(row + 2, MONTH_HOURS_COL, "часы", format)?;
+    let two_rows = [
+        (PAY_CODE_COL, "код вида оплаты"),
+        (ACCOUNT_COL, "корреспондирующий счет"),
+        (PAY_DAYS_COL, "дни (часы)"),
+        (ABSENCE_COLS[0], "код"),
+        (ABSENCE_COLS[0] + 1, "дни (часы)"),
+        (ABSENCE_COLS[1], "код"),
+        (ABSENCE_COLS[1] + 1, "дни (часы)"),
+    ];
+    for (col, text) in two_rows {
+        write_range(worksheet, (row + 1, col), (row + 2, col), text, format)?;
+    }
+
+    // Form column numbers
+    let numbers = [
+        (NUMBER_COL, NUMBER_COL),
+        (NAME_COL, NAME_COL),
+        (PERSONNEL_COL, PERSONNEL_COL),
+        (FIRST_DAY_COL, last_day_col),
+        (HALF_COL, HALF_COL),
+        (MONTH_DAYS_COL, MONTH_HOURS_COL),
+        (PAY_CODE_COL, PAY_CODE_COL),
+        (ACCOUNT_COL, ACCOUNT_COL),
+        (PAY_DAYS_COL, PAY_DAYS_COL),
+        (ABSENCE_COLS[0], ABSENCE_COLS[0]),
+        (ABSENCE_COLS[0] + 1, ABSENCE_COLS[0] + 1),
+        (ABSENCE_COLS[1], ABSENCE_COLS[1]),
+        (ABSENCE_COLS[1] + 1, ABSENCE_COLS[1] + 1),
+    ];
+    for (number, (first, last)) in numbers.into_iter().enumerate() {
+        write_range(
+            worksheet,
+            (row + 3, first),
+            (row + 3, last),
+            &(number + 1).to_string(),
+            format,
+        )?;
+    }
+    Ok(())
+}
+
+// Employee takes four rows, codes above hours for each half of month
+fn add_employee_rows(
+    worksheet: &mut Worksheet,
+    formats: &Formats,
+    month: &Month,
+    schedule: &Schedule,
+    form: &FormFields,
+) -> EResult<()> {
+    let row = EMPLOYEE_ROW;
+    let last_row = row + 3;
+    let format = &formats.cell;
+    let marks = month_marks(month, schedule);
+
+    write_range(
+        worksheet,
+        (row, NUMBER_COL),
+        (last_row, NUMBER_COL),
+        "1",
+        format,
+    )?;
+    let employee = [&form.employee_name, &form.position]
+        .into_iter()
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    write_range(
+        worksheet,
+        (row, NAME_COL),
+        (last_row, NAME_COL),
+        &employee,
+        format,
+    )?;
+    write_range(
+        worksheet,
+        (row, PERSONNEL_COL),
+        (last_row, PERSONNEL_COL),
+        field(&form.personnel_number),
+        format,
+    )?;
+
+    let halves = [&marks[..HALF_DAYS as usize], &marks[HALF_DAYS as usize..]];
+    for (half, half_marks) in halves.iter().enumerate() {
+        let code_row = row + 2 * half as RowNum;
+        // Days after month end are crossed
+        for index in 0..=HALF_DAYS as usize {
+            let col = FIRST_DAY_COL + index as ColNum;
+            let (code, hours) = match half_marks.get(index) {
+                Some(Some(mark)) => (mark.code_text(), mark.hours_text()),
+                Some(None) => ("".to_string(), "".to_string()),
+                None => ("X".to_string(), "X".to_string()),
+            };
+            worksheet.write_with_format(code_row, col, code, format)?;
+            worksheet.write_with_format(code_row + 1, col, hours, format)?;
+        }
+        let (days, worked) = worked_totals(half_marks);
+        worksheet.write_with_format(code_row, HALF_COL, days as u32, format)?;
+        worksheet.write_with_format(code_row + 1, HALF_COL, hours(worked), format)?;
+    }
+    let (days, worked) = worked_totals(&marks);
+    write_range(
+        worksheet,
+        (row, MONTH_DAYS_COL),
+        (last_row, MONTH_DAYS_COL),
+        &days.to_string(),
+        format,
+    )?;
+    write_range(
+        worksheet,
+        (row, MONTH_HOURS_COL),
+        (last_row, MONTH_HOURS_COL),
+        &hours(worked),
+        format,
+    )?;
+    // Pay data is filled by accounting
+    for col in [PAY_CODE_COL, ACCOUNT_COL, PAY_DAYS_COL] {
+        write_range(worksheet, (row, col), (last_row, col), "", format)?;
+    }
+
+    // Absence codes with days, two entries per column pair
+    let absences = absence_days(&marks);
+    for slot in 0..2 * ABSENCE_COLS.len() {
+        let col = ABSENCE_COLS[slot / 2];
+        let first_row = row + 2 * (slot % 2) as RowNum;
+        let (code, days) = match absences.get(slot) {
+            Some((code, days)) => (code.code(), days.to_string()),
+            None => ("", "".to_string()),
+        };
+        write_range(
+            worksheet,
+            (first_row, col),
+            (first_row + 1, col),
+            code,
+            format,
+        )?;
+        write_range(
+            worksheet,
+            (first_row, col + 1),
+            (first_row + 1, col + 1),
+            &days,
+            format,
+        )?;
+    }
+    Ok(())
+}
+
+// Marks by day number, days out of period or employment stay empty
+fn month_marks(month: &Month, schedule: &Schedule) -> Vec<Option<DayMark>> {
+    let month_start = month.first_day().date().with_day(1).unwrap();
+    let month_length = (month_start + Months::new(1) - chrono::Days::new(1)).day();
+    let mut marks: Vec<Option<DayMark>> = (0..month_length).map(|_| None).collect();
+    for (day, worked) in month.days().iter().zip(month.hours()) {
+        if day.employed() {
+            marks[day.number() as usize - 1] = Some(DayMark::new(day, *worked, schedule));
+        }
+    }
+    marks
+}
+
+// Absence codes in form order with their days, codes without days are skipped
+fn absence_days(marks: &[Option<DayMark>]) -> Vec<(DayCode, usize)> {
+    DayCode::ABSENCES
+        .into_iter()
+        .map(|code| {
+            let days = marks
+                .iter()
+                .flatten()
+                .filter(|m| m.absence() == Some(code))
+                .count();
+            (code, days)
+        })
+        .filter(|(_, days)| *days > 0)
+        .collect()
+}
+
+// Worked days and hours, days with attendance or weekend work are counted
+fn worked_totals(marks: &[Option<DayMark>]) -> (usize, f64) {
+    let worked: Vec<&DayMark> = marks
+        .iter()
+        .flatten()
+        .filter(|m| m.worked_hours() > 0.0)
+        .collect();
+    (worked.len(), worked.iter().map(|m| m.worked_hours()).sum())
+}
+
+fn add_signatures(worksheet: &mut Worksheet, formats: &Formats) -> EResult<()> {
+    let signers = [
+        "Ответственное лицо",
+        "Руководитель структурного подразделения",
+        "Работник кадровой службы",
+    ];
+    // Position, signature and its transcript lines
+    let lines = [
+        ((4, 9), "(должность)"),
+        ((11, 15), "(личная подпись)"),
+        ((17, 22), "(расшифровка подписи)"),
+    ];
+    for (index, signer) in signers.into_iter().enumerate() {
+        let row = SIGNATURES_ROW + 3 * index as RowNum;
+        write_range(
+            worksheet,
+            (row, NUMBER_COL),
+            (row, PERSONNEL_COL),
+            signer,
+            &formats.label,
+        )?;
+        for ((first, last), caption) in lines {
+            write_range(worksheet, (row, first), (row, last), "", &formats.field)?;
+            write_range(
+                worksheet,
+                (row + 1, first),
+                (row + 1, last),
+                caption,
+                &formats.caption,
+            )?;
+        }
+        write_range(
+            worksheet,
+            (row, 24),
+            (row, LAST_COL),
+            "«___» __________ 20__ г.",
+            &formats.cell.clone().set_border(FormatBorder::None),
+        )?;
+    }
+    Ok(())
+}
+
+fn add_code_legend(worksheet: &mut Worksheet, formats: &Formats) -> EResult<()> {
+    let row = SIGNATURES_ROW + 10;
+    let format = formats.cell.clone().set_border(FormatBorder::None);
+    let text_format = format.clone().set_align(FormatAlign::Left);
+    write_range(
+        worksheet,
+        (row, NUMBER_COL),
+        (row, PERSONNEL_COL),
+        "Условные обозначения:",
+        &text_format,
+    )?;
+    for (index, code) in DayCode::ALL.iter().enumerate() {
+        let code_row = row + 1 + index as RowNum;
+        worksheet.write_with_format(
+            code_row,
+            NUMBER_COL,
+            code.code(),
+            &format.clone().set_bold(),
+        )?;
+        write_range(
+            worksheet,
+            (code_row, NAME_COL),
+            (code_row, HALF_COL),
+            code.description(),
+            &text_format,
+        )?;
+    }
+    Ok(())
+}
+
+fn setup_sheet(worksheet: &mut Worksheet) -> EResult<()> {
+    worksheet.set_screen_gridlines(false);
+    worksheet.set_column_width(NUMBER_COL, 5)?;
+    worksheet.set_column_width(NAME_COL, 22)?;
+    worksheet.set_column_width(PERSONNEL_COL, 9)?;
+    worksheet.set_column_range_width(FIRST_DAY_COL, FIRST_DAY_COL + HALF_DAYS as ColNum, 4.5)?;
+    worksheet.set_column_range_width(HALF_COL, LAST_COL, 7)?;
+    worksheet.set_row_height(TABLE_HEADER_ROW, 24)?;
+    worksheet.set_row_height(TABLE_HEADER_ROW + 1, 30)?;
+    // Form is printed at A4 landscape on a single page
+    worksheet.set_paper_size(9).set_landscape();
+    worksheet.set_print_fit_to_pages(1, 1);
+    Ok(())
+}
+
+// Merges cells if range is wider than a cell
+fn write_range(
+    worksheet: &mut Worksheet,
+    first: (RowNum, ColNum),
+    last: (RowNum, ColNum),
+    text: &str,
+    format: &Format,
+) -> EResult<()> {
+    if first == last {
+        worksheet.write_with_format(first.0, first.1, text, format)?;
+    } else {
+        worksheet.merge_range(first.0, first.1, last.0, last.1, text, format)?;
+    }
+    Ok(())
+}
+
+fn field(value: &Option<String>) -> &str {
+    value.as_deref().unwrap_or_default()
+}
+
+// Hours like 8 or 7,5
+fn hours(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.1}", value).replace('.', ",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::excel::{
+        data::days::Days,
+        settings::{PayRules, Period},
+    };
+
+    fn mark(day_type: DayType, worked: f64) -> (String, String) {
+        let day = Day::new(NaiveDate::from_ymd_opt(2026, 3, 2).unwrap(), day_type);
+        let mark = DayMark::new(&day, worked, &Schedule::default());
+        (mark.code_text(), mark.hours_text())
+    }
+
+    fn text(code: &str, hours: &str) -> (String, String) {
+        (code.to_string(), hours.to_string())
+    }
+
+    // March 2026 with weekends as days off, hours are set by day number
+    fn march(day_types: &[(usize, DayType)], hours: &[(usize, f64)]) -> Timesheet {
+        let mut days = Days::new(&Period::month(2026, 3).unwrap(), &HashSet::new());
+        for (number, day_type) in day_types {
+            days[number - 1] = Day::new(days[number - 1].date(), *day_type);
+        }
+        let mut timesheet = Timesheet::new(&days, 0, PayRules::default(), Schedule::default());
+        let mut month_hours = vec![0.0; days.len()];
+        for (number, worked) in hours {
+            month_hours[number - 1] = *worked;
+        }
+        timesheet.set_hours(3, &month_hours).unwrap();
+        timesheet
+    }
+
+    #[test]
+    fn day_types_have_form_codes() {
+        assert_eq!(mark(DayType::Usual, 0.0), text("Я", "8"));
+        assert_eq!(mark(DayType::RestDay, 0.0), text("В", ""));
+        assert_eq!(mark(DayType::Holiday, 0.0), text("В", ""));
+        assert_eq!(mark(DayType::RestDay, 5.0), text("РВ", "5"));
+        assert_eq!(mark(DayType::Holiday, 7.5), text("РВ", "7,5"));
+        assert_eq!(mark(DayType::Vacation, 0.0), text("ОТ", ""));
+        assert_eq!(mark(DayType::SickLeave, 0.0), text("Б", ""));
+    }
+
+    #[test]
+    fn overtime_is_joined_with_attendance() {
+        assert_eq!(mark(DayType::Usual, 2.0), text("Я/С", "8/2"));
+    }
+
+    #[test]
+    fn halves_of_month_are_totalled() {
+        // Overtime at Monday 2nd, work at Saturday 21st
+        let timesheet = march(&[], &[(2, 2.0), (21, 4.0)]);
+        let marks = month_marks(&timesheet.months()[0], timesheet.schedule());
+        let (first_half, second_half) = marks.split_at(HALF_DAYS as usize);
+        assert_eq!(worked_totals(first_half), (10, 82.0));
+        assert_eq!(worked_totals(second_half), (13, 100.0));
+        assert_eq!(worked_totals(&marks), (23, 182.0));
+    }
+
+    #[test]
+    fn absences_fill_slots_in_form_order() {
+        // Sick leave goes before vacation at month, form order is kept
+        let day_types = [
+            (3, DayType::SickLeave),
+            (10, DayType::Vacation),
+            (11, DayType::Vacation),
+        ];
+        let timesheet = march(&day_types, &[(21, 4.0)]);
+        let marks = month_marks(&timesheet.months()[0], timesheet.schedule());
+        // Nine weekend days, one of them worked
+        assert_eq!(
+            absence_days(&marks),
+            vec![
+                (DayCode::DayOff, 8),
+                (DayCode::Vacation, 2),
+                (DayCode::SickLeave, 1)
+            ]
+        );
+        let timesheet = march(&[], &[]);
+        let marks = month_marks(&timesheet.months()[0], timesheet.schedule());
+        assert_eq!(absence_days(&marks), vec![(DayCode::DayOff, 9)]);
+    }
+}
@@ -0,0 +1,372 @@
+use rust_xlsxwriter::{
+    ColNum, Formula, RowNum,
+    utility::{cell_range, cell_range_absolute, row_col_to_cell},
+    workbook::Workbook,
+    worksheet::Worksheet,
+};
+
+use crate::excel::{
+    data::{
+        days::{Day, DayType, Days, NOT_EMPLOYED_MARK, Season},
+        headers::APP_INFO,
+        names::{define_rule_names, rate_name},
+        summary::YEAR_TOTAL,
+    },
+    error::Result as EResult,
+    settings::{Employment, Schedule, Settings},
+    styles::{CellType, DataType, cell_style},
+    xlsx::protect_worksheet,
+};
+
+/// Employee row of team table
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Employee {
+    pub name: String,
+    pub personnel_number: Option<String>,
+    /// Monthly salary, zero if unknown
+    pub salary: u32,
+    pub schedule: Schedule,
+    /// Days out of employment are locked and salary is prorated
+    pub employment: Employment,
+}
+
+// Employee columns at left, a column per day and totals at right
+const NAME_COL: ColNum = 0;
+const PERSONNEL_COL: ColNum = 1;
+const SALARY_COL: ColNum = 2;
+const FIRST_DAY_COL: ColNum = 3;
+
+// Title, day headers and day types go before employee rows
+const TITLE_ROW: RowNum = 0;
+const HEADER_ROW: RowNum = 1;
+const TYPE_ROW: RowNum = 2;
+const FIRST_EMPLOYEE_ROW: RowNum = 3;
+
+// Total columns after days from left to right
+#[derive(Debug, Clone, Copy)]
+enum TeamColumn {
+    NormHours,
+    OvertimeHours,
+    WeekendHours,
+    HourRate,
+    Bonus,
+    Payout,
+}
+
+impl TeamColumn {
+    const ALL: [TeamColumn; 6] = [
+        TeamColumn::NormHours,
+        TeamColumn::OvertimeHours,
+        TeamColumn::WeekendHours,
+        TeamColumn::HourRate,
+        TeamColumn::Bonus,
+        TeamColumn::Payout,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            TeamColumn::NormHours => "Норма часов",
+            TeamColumn::OvertimeHours => "Переработка",
+            TeamColumn::WeekendHours => "Часы выходных",
+            TeamColumn::HourRate => "Ставка в час",
+            TeamColumn::Bonus => "Доплата",
+            TeamColumn::Payout => "К получению",
+        }
+    }
+
+    fn col(&self, total_days: usize) -> ColNum {
+        FIRST_DAY_COL + total_days as ColNum + *self as ColNum
+    }
+}
+
+// Month sheets with a row per employee, calendar is shared by all rows
+pub(crate) fn render_team(
+    days: &Days,
+    employees: &[Employee],
+    settings: &Settings,
+) -> EResult<Vec<u8>> {
+    let mut table = Workbook::new();
+    // Pay rules are shared by all months
+    define_rule_names(&mut table, &settings.pay_rules, &settings.schedule)?;
+    for month_days in days.split_months() {
+        let worksheet = table.add_worksheet();
+        worksheet.set_name(month_days.first().unwrap().month_name(settings.locale))?;
+        add_title_cells(worksheet, month_days, settings)?;
+        add_day_headers(worksheet, month_days, settings)?;
+        for (index, employee) in employees.iter().enumerate() {
+            let row = FIRST_EMPLOYEE_ROW + index as RowNum;
+            add_employee_cells(worksheet, row, employee, month_days, settings)?;
+        }
+        let total_row = FIRST_EMPLOYEE_ROW + employees.len() as RowNum;
+        add_team_totals(worksheet, total_row, month_days.len(), settings)?;
+        setup_sheet(worksheet, month_days.len(), settings)?;
+    }
+    let buf = table.save_to_buffer()?;
+    Ok(buf)
+}
+
+fn add_title_cells(
+    worksheet: &mut Worksheet,
+    month_days: &[Day],
+    settings: &Settings,
+) -> EResult<()> {
+    let theme = &settings.theme;
+    let first_day = month_days.first().unwrap();
+    let last_col = TeamColumn::Payout.col(month_days.len());
+    let format = cell_style(theme, DataType::UsualText, CellType::Header);
+    worksheet.write_with_format(TITLE_ROW, NAME_COL, first_day.year(), &format)?;
+    worksheet.merge_range(
+        TITLE_ROW,
+        PERSONNEL_COL,
+        TITLE_ROW,
+        SALARY_COL,
+        APP_INFO,
+        &format,
+    )?;
+    let cell_type = match first_day.season() {
+        Season::Winter => CellType::MonthWinter,
+        Season::Spring => CellType::MonthSpring,
+        Season::Summer => CellType::MonthSummer,
+        Season::Autumn => CellType::MonthAutumn,
+    };
+    worksheet.merge_range(
+        TITLE_ROW,
+        FIRST_DAY_COL,
+        TITLE_ROW,
+        last_col,
+        first_day.month_name(settings.locale).as_str(),
+        &cell_style(theme, DataType::UsualText, cell_type),
+    )?;
+    Ok(())
+}
+
+fn add_day_headers(
+    worksheet: &mut Worksheet,
+    month_days: &[Day],
+    settings: &Settings,
+) -> EResult<()> {
+    let theme = &settings.theme;
+    let mut format = cell_style(theme, DataType::UsualText, CellType::Header);
+    let employee_headers = [(NAME_COL, "Сотрудник"), (PERSONNEL_COL, "Таб. номер")];
+    for (col, header) in employee_headers {
+        worksheet.merge_range(HEADER_ROW, col, TYPE_ROW, col, header, &format)?;
+    }
+    for column in TeamColumn::ALL {
+        let col = column.col(month_days.len());
+        worksheet.merge_range(HEADER_ROW, col, TYPE_ROW, col, column.label(), &format)?;
+    }
+    format = cell_style(theme, DataType::UsualText, CellType::InputHeader);
+    worksheet.merge_range(
+        HEADER_ROW,
+        SALARY_COL,
+        TYPE_ROW,
+        SALARY_COL,
+        "Оклад",
+        &format,
+    )?;
+    // Day number with weekday above day type, coloured like day rows of month sheet
+    for (index, day) in month_days.iter().enumerate() {
+        let col = FIRST_DAY_COL + index as ColNum;
+        format = cell_style(theme, DataType::UsualText, day.day_type().cell_type());
+        worksheet.write_with_format(
+            HEADER_ROW,
+            col,
+            format!("{} {}", day.number(), day.weekday_short(settings.locale)),
+            &format.clone().set_text_wrap(),
+        )?;
+        worksheet.write_with_format(
+            TYPE_ROW,
+            col,
+            day.day_type().name(),
+            &format.set_rotation(90),
+        )?;
+    }
+    Ok(())
+}
+
+fn add_employee_cells(
+    worksheet: &mut Worksheet,
+    row: RowNum,
+    employee: &Employee,
+    month_days: &[Day],
+    settings: &Settings,
+) -> EResult<()> {
+    let theme = &settings.theme;
+    let total_days = month_days.len();
+    let last_day_col = FIRST_DAY_COL + total_days as ColNum - 1;
+    let col = |column: TeamColumn| row_col_to_cell(row, column.col(total_days));
+    let types = cell_range_absolute(TYPE_ROW, FIRST_DAY_COL, TYPE_ROW, last_day_col);
+    let hours = cell_range(row, FIRST_DAY_COL, row, last_day_col);
+    let salary = row_col_to_cell(row, SALARY_COL);
+
+    let mut format = cell_style(theme, DataType::UsualText, CellType::Header);
+    worksheet.write_with_format(row, NAME_COL, &employee.name, &format)?;
+    let personnel_number = employee.personnel_number.as_deref().unwrap_or_default();
+    worksheet.write_with_format(row, PERSONNEL_COL, personnel_number, &format)?;
+    // Salary input, stays empty if unknown
+    format = cell_style(theme, DataType::Money, CellType::InputHeader).set_unlocked();
+    match employee.salary {
+        0 => worksheet.write_blank(row, SALARY_COL, &format)?,
+        salary => worksheet.write_with_format(row, SALARY_COL, salary, &format)?,
+    };
+    // Hours are input cells coloured by day type, days out of employment are locked
+    // Days out of period are locked too
+    let employment = &employee.employment;
+    let counted = |day: &Day| day.employed() && employment.contains(day.date());
+    for (index, day) in month_days.iter().enumerate() {
+        let col = FIRST_DAY_COL + index as ColNum;
+        match counted(day) {
+            true => {
+                format = cell_style(theme, DataType::UsualText, day.day_type().cell_type())
+                    .set_unlocked();
+                worksheet.write_with_format(row, col, 0, &format)?
+            }
+            false => {
+                format = cell_style(theme, DataType::UsualText, CellType::NotEmployed);
+                worksheet.write_with_format(row, col, NOT_EMPLOYED_MARK, &format)?
+            }
+        };
+    }
+    let prorated = month_days.iter().any(|day| !counted(day));
+    // Norm of whole month, rate does not change with employment
+    let full_norm = format!(
+        "{}*COUNTIF({},\"{}\")",
+        employee.schedule.work_day_hours,
+        types,
+        DayType::Usual.name()
+    );
+
+    let sumif = |day_type: DayType| format!("SUMIF({},\"{}\",{})", types, day_type.name(), hours);
+    format = cell_style(theme, DataType::UsualText, CellType::Header);
+    let norm = match prorated {
+        true => format!(
+            "={}*COUNTIFS({},\"{}\",{},\"<>{}\")",
+            employee.schedule.work_day_hours,
+            types,
+            DayType::Usual.name(),
+            hours,
+            NOT_EMPLOYED_MARK
+        ),
+        false => format!("={}", full_norm),
+    };
+    let cells = [
+        (TeamColumn::NormHours, norm),
+        (
+            TeamColumn::OvertimeHours,
+            format!("={}", sumif(DayType::Usual)),
+        ),
+        (
+            TeamColumn::WeekendHours,
+            format!("={}+{}", sumif(DayType::RestDay), sumif(DayType::Holiday)),
+        ),
+    ];
+    for (column, formula) in cells {
+        let cell_col = column.col(total_days);
+        worksheet.write_formula_with_format(row, cell_col, Formula::new(formula), &format)?;
+    }
+    format = cell_style(theme, DataType::Money, CellType::Header);
+    worksheet.write_formula_with_format(
+        row,
+        TeamColumn::HourRate.col(total_days),
+        Formula::new(match prorated {
+            true => format!("=IF({norm}>0,{}/({norm}),0)", salary, norm = full_norm),
+            false => format!(
+                "=IF({norm}>0,{}/{norm},0)",
+                salary,
+                norm = col(TeamColumn::NormHours)
+            ),
+        }),
+        &format,
+    )?;
+    // Hours of every day type with its multiplier
+    let multiplied = DayType::ALL
+        .iter()
+        .map(|day_type| format!("{}*{}", rate_name(day_type), sumif(*day_type)))
+        .collect::<Vec<String>>()
+        .join("+");
+    format = cell_style(theme, DataType::Money, CellType::TotalBonus);
+    worksheet.write_formula_with_format(
+        row,
+        TeamColumn::Bonus.col(total_days),
+        Formula::new(format!("={}*({})", col(TeamColumn::HourRate), multiplied)),
+        &format,
+    )?;
+    // Prorated salary is paid for norm hours
+    let salary = match prorated {
+        true => format!(
+            "{}*{}",
+            col(TeamColumn::HourRate),
+            col(TeamColumn::NormHours)
+        ),
+        false => salary,
+    };
+    format = cell_style(theme, DataType::Money, CellType::TotalPayment);
+    worksheet.write_formula_with_format(
+        row,
+        TeamColumn::Payout.col(total_days),
+        Formula::new(format!("={}+{}", salary, col(TeamColumn::Bonus))),
+        &format,
+    )?;
+    Ok(())
+}
+
+// Team totals of money columns under employee rows
+fn add_team_totals(
+    worksheet: &mut Worksheet,
+    total_row: RowNum,
+    total_days: usize,
+    settings: &Settings,
+) -> EResult<()> {
+    let theme = &settings.theme;
+    let format = cell_style(theme, DataType::UsualText, CellType::Header);
+    worksheet.merge_range(
+        total_row,
+        NAME_COL,
+        total_row,
+        PERSONNEL_COL,
+        YEAR_TOTAL,
+        &format,
+    )?;
+    let columns = [
+        (SALARY_COL, CellType::Header),
+        (TeamColumn::Bonus.col(total_days), CellType::TotalBonus),
+        (TeamColumn::Payout.col(total_days), CellType::TotalPayment),
+    ];
+    for (col, cell_type) in columns {
+        let range = cell_range(FIRST_EMPLOYEE_ROW, col, total_row - 1, col);
+        worksheet.write_formula_with_format(
+            total_row,
+            col,
+            Formula::new(format!("=SUM({})", range)),
+            &cell_style(theme, DataType::Money, cell_type),
+        )?;
+    }
+    Ok(())
+}
+
+fn setup_sheet(worksheet: &mut Worksheet, total_days: usize, settings: &Settings) -> EResult<()> {
+    worksheet.set_screen_gridlines(false);
+    worksheet.set_column_width(NAME_COL, 24)?;
+    worksheet.set_column_width(PERSONNEL_COL, 10)?;
+    worksheet.set_column_width(SALARY_COL, 12)?;
+    let last_day_col = FIRST_DAY_COL + total_days as ColNum - 1;
+    worksheet.set_column_range_width(FIRST_DAY_COL, last_day_col, 5)?;
+    worksheet.set_column_range_width(
+        TeamColumn::NormHours.col(total_days),
+        TeamColumn::Payout.col(total_days),
+        13,
+    )?;
+    worksheet.set_row_height(HEADER_ROW, 30)?;
+    worksheet.set_row_height(TYPE_ROW, 62)?;
+    // Employees and days stay visible while scrolling
+    worksheet.set_freeze_panes(FIRST_EMPLOYEE_ROW, FIRST_DAY_COL)?;
+    // A4 landscape, one page wide with repeated day headers
+    worksheet.set_paper_size(9).set_landscape();
+    worksheet.set_print_fit_to_pages(1, 0);
+    worksheet.set_repeat_rows(HEADER_ROW, TYPE_ROW)?;
+    worksheet.set_footer("&RСтраница &P из &N");
+    if let Some(protection) = &settings.protection {
+        protect_worksheet(worksheet, protection);
+    }
+    Ok(())
+}
@@ -0,0 +1,74 @@
+/// Background colours of table cells as 0xRRGGBB
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub header: u32,
+    pub input: u32,
+    pub payment: u32,
+    pub rest_day: u32,
+    pub holiday: u32,
+    pub vacation: u32,
+    pub sick_leave: u32,
+    /// Days before hire and after termination
+    pub not_employed: u32,
+    pub winter: u32,
+    pub spring: u32,
+    pub summer: u32,
+    pub autumn: u32,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            header: 0xEDCAE9,
+            input: 0xF0C2A7,
+            payment: 0xB2E19E,
+            rest_day: 0xF8B9B8,
+            holiday: 0xB2E19E,
+            vacation: 0xBDD7EE,
+            sick_leave: 0xE4DFEC,
+            not_employed: 0xD9D9D9,
+            winter: 0xC6E8F4,
+            spring: 0xB2E19E,
+            summer: 0xFFE699,
+            autumn: 0xF0C1A7,
+        }
+    }
+}
+
+impl Theme {
+    /// Grey shades for black and white printing, day types differ by lightness
+    pub fn print() -> Self {
+        Self {
+            header: 0xFFFFFF,
+            input: 0xF2F2F2,
+            payment: 0xD9D9D9,
+            rest_day: 0xBFBFBF,
+            holiday: 0x8C8C8C,
+            vacation: 0xE6E6E6,
+            sick_leave: 0xA6A6A6,
+            not_employed: 0x737373,
+            winter: 0xFFFFFF,
+            spring: 0xFFFFFF,
+            summer: 0xFFFFFF,
+            autumn: 0xFFFFFF,
+        }
+    }
+
+    /// Light tints of Okabe-Ito palette, safe for red-green colour blindness
+    pub fn color_blind() -> Self {
+        Self {
+            header: 0xDDDDDD,
+            input: 0xF5D5A0,
+            payment: 0x9FD9C6,
+            rest_day: 0xF2B38C,
+            holiday: 0x9DC9EA,
+            vacation: 0xF6EFA3,
+            sick_leave: 0xE3B7D1,
+            not_employed: 0xBBBBBB,
+            winter: 0xB7DCF3,
+            spring: 0x9FD9C6,
+            summer: 0xF6EFA3,
+            autumn: 0xF5D5A0,
+        }
+    }
+}
@@ -0,0 +1,339 @@
+pub use crate::excel::data::days::{Day, DayType};
+
+use chrono::Datelike;
+
+use crate::excel::{
+    data::days::Days,
+    error::{Error, Result as EResult},
+    settings::{Locale, PayRules, Schedule},
+};
+
+/// Year timesheet computed in Rust, every renderer draws it
+#[derive(Debug, Clone)]
+pub struct Timesheet {
+    year: i32,
+    salary: u32,
+    pay_rules: PayRules,
+    schedule: Schedule,
+    months: Vec<Month>,
+}
+
+impl Timesheet {
+    pub(crate) fn new(days: &Days, salary: u32, pay_rules: PayRules, schedule: Schedule) -> Self {
+        let months = days
+            .split_months()
+            .map(|month_days| Month::new(month_days, salary, &pay_rules, &schedule))
+            .collect();
+        Self {
+            year: days.first().unwrap().year(),
+            salary,
+            pay_rules,
+            schedule,
+            months,
+        }
+    }
+
+    /// Year of first month
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Year like "2026", or both years like "2025-2026" if period crosses new year
+    pub fn title(&self) -> String {
+        let last_year = self.months.last().unwrap().first_day().year();
+        match last_year == self.year {
+            true => self.year.to_string(),
+            false => format!("{}-{}", self.year, last_year),
+        }
+    }
+
+    /// Monthly salary given at generation, zero if unknown
+    pub fn salary(&self) -> u32 {
+        self.salary
+    }
+
+    pub fn pay_rules(&self) -> &PayRules {
+        &self.pay_rules
+    }
+
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    pub fn months(&self) -> &[Month] {
+        &self.months
+    }
+
+    /// Set worked hours per day of month (1-12) and recompute its totals
+    pub fn set_hours(&mut self, month: u32, hours: &[f64]) -> EResult<()> {
+        let (pay_rules, schedule) = (self.pay_rules, self.schedule);
+        let target = self.month_mut(month)?;
+        if hours.len() != target.days.len() {
+            return Err(Error::InvalidOptions(format!(
+                "Month {month} has {} days, got {} hours!",
+                target.days.len(),
+                hours.len()
+            )));
+        }
+        target.hours = hours.to_vec();
+        target.totals = MonthTotals::new(&target.days, hours, target.salary, &pay_rules, &schedule);
+        Ok(())
+    }
+
+    /// Set salary of month (1-12) and recompute its totals
+    pub fn set_salary(&mut self, month: u32, salary: u32) -> EResult<()> {
+        let (pay_rules, schedule) = (self.pay_rules, self.schedule);
+        let target = self.month_mut(month)?;
+        target.salary = salary;
+        target.totals =
+            MonthTotals::new(&target.days, &target.hours, salary, &pay_rules, &schedule);
+        Ok(())
+    }
+
+    fn month_mut(&mut self, month: u32) -> EResult<&mut Month> {
+        match self.months.iter_mut().find(|m| m.number() == month) {
+            Some(target) => Ok(target),
+            None => Err(Error::InvalidOptions(format!("Cannot find month {month}!"))),
+        }
+    }
+}
+
+/// Days of month with worked hours and totals
+#[derive(Debug, Clone)]
+pub struct Month {
+    days: Vec<Day>,
+    hours: Vec<f64>,
+    salary: u32,
+    totals: MonthTotals,
+}
+
+impl Month {
+    // Generated table has no worked hours yet
+    fn new(days: &[Day], salary: u32, pay_rules: &PayRules, schedule: &Schedule) -> Self {
+        let hours = vec![0.0; days.len()];
+        let totals = MonthTotals::new(days, &hours, salary, pay_rules, schedule);
+        Self {
+            days: days.to_vec(),
+            hours,
+            salary,
+            totals,
+        }
+    }
+
+    /// Month name like "Январь"
+    pub fn title(&self, locale: Locale) -> &'static str {
+        self.first_day().month_title(locale)
+    }
+
+    /// Month number from 1
+    pub fn number(&self) -> u32 {
+        self.first_day().date().month()
+    }
+
+    // Month name with emoji, used as sheet name
+    pub(crate) fn name(&self, locale: Locale) -> String {
+        self.first_day().month_name(locale)
+    }
+
+    pub(crate) fn first_day(&self) -> &Day {
+        self.days.first().unwrap()
+    }
+
+    pub fn days(&self) -> &[Day] {
+        &self.days
+    }
+
+    // Some days are out of employment, salary is prorated
+    pub(crate) fn prorated(&self) -> bool {
+        self.days.iter().any(|day| !day.employed())
+    }
+
+    // First and last rows of employed days, rows start from 1
+    pub(crate) fn employed_rows(&self) -> (u32, u32) {
+        let first = self.days.iter().position(|day| day.employed()).unwrap_or(0);
+        let last = self
+            .days
+            .iter()
+            .rposition(|day| day.employed())
+            .unwrap_or(0);
+        (first as u32 + 1, last as u32 + 1)
+    }
+
+    /// Worked hours per day
+    pub fn hours(&self) -> &[f64] {
+        &self.hours
+    }
+
+    /// Salary of month, zero if unknown
+    pub fn salary(&self) -> u32 {
+        self.salary
+    }
+
+    pub fn totals(&self) -> &MonthTotals {
+        &self.totals
+    }
+}
+
+/// Month totals, same as total block formulas
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MonthTotals {
+    /// Planned hours of working days
+    pub norm_hours: f64,
+    /// Hours worked at working days
+    pub overtime_hours: f64,
+    /// Hours worked at rest days and holidays
+    pub weekend_hours: f64,
+    pub hour_rate: f64,
+    /// Salary prorated to employed days
+    pub salary: f64,
+    /// Pay for overtime and weekend hours
+    pub bonus: f64,
+    /// Salary with bonus
+    pub payout: f64,
+}
+
+impl MonthTotals {
+    // Hours are worked hours per day of month
+    pub(crate) fn new(
+        days: &[Day],
+        hours: &[f64],
+        salary: u32,
+        pay_rules: &PayRules,
+        schedule: &Schedule,
+    ) -> Self {
+        let norm_hours: f64 = days.iter().map(|day| day.planned_hours(schedule)).sum();
+        // Rate comes from norm of whole month, so salary is prorated out of employment
+        let prorated = days.iter().any(|day| !day.employed());
+        let (paid_norm_hours, full_norm_hours) = match prorated {
+            true => prorated_norm_hours(days, schedule),
+            false => (norm_hours, norm_hours),
+        };
+        let (hour_rate, salary) = if full_norm_hours > 0.0 {
+            let hour_rate = salary as f64 / full_norm_hours;
+            match prorated {
+                true => (hour_rate, hour_rate * paid_norm_hours),
+                false => (hour_rate, salary as f64),
+            }
+        } else {
+            (0.0, salary as f64)
+        };
+        let mut overtime_hours = 0.0;
+        let mut weekend_hours = 0.0;
+        let mut bonus = 0.0;
+        for (day, hours) in days.iter().zip(hours) {
+            if !day.employed() {
+                continue;
+            }
+            let day_type = day.day_type();
+            match day_type {
+                DayType::Usual => overtime_hours += hours,
+                DayType::RestDay | DayType::Holiday => weekend_hours += hours,
+                DayType::Vacation | DayType::SickLeave => {}
+            }
+            bonus += Self::day_bonus(hour_rate, *hours, day_type, pay_rules);
+        }
+        Self {
+            norm_hours,
+            overtime_hours,
+            weekend_hours,
+            hour_rate,
+            salary,
+            bonus,
+            payout: salary + bonus,
+        }
+    }
+
+    pub(crate) fn day_bonus(
+        hour_rate: f64,
+        hours: f64,
+        day_type: DayType,
+        pay_rules: &PayRules,
+    ) -> f64 {
+        hour_rate * hours * day_type.multiplier(pay_rules)
+    }
+
+    /// Norm with overtime and weekend hours, like summary sheet
+    pub fn worked_hours(&self) -> f64 {
+        self.norm_hours + self.overtime_hours + self.weekend_hours
+    }
+}
+
+// Norm of employed days and norm of whole month, vacation and sick leave are paid as work days
+// like at whole month, days out of employment have day type hours
+fn prorated_norm_hours(days: &[Day], schedule: &Schedule) -> (f64, f64) {
+    let (mut paid, mut not_employed) = (0.0, 0.0);
+    for day in days {
+        match (day.employed(), day.day_type()) {
+            (true, DayType::Vacation | DayType::SickLeave) => {
+                paid += day.planned_hours(schedule) + schedule.work_day_hours
+            }
+            (true, _) => paid += day.planned_hours(schedule),
+            (false, day_type) => not_employed += day_type.planned_hours(schedule),
+        }
+    }
+    (paid, paid + not_employed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use chrono::NaiveDate;
+
+    use super::*;
+    use crate::excel::settings::{Employment, Period};
+
+    #[test]
+    fn months_cut_by_period_keep_rate_of_whole_month() {
+        let period = Period::new(
+            NaiveDate::from_ymd_opt(2025, 11, 17).unwrap(),
+            NaiveDate::from_ymd_opt(2026, 2, 10).unwrap(),
+        )
+        .unwrap();
+        let days = Days::new(&period, &HashSet::new());
+        let mut timesheet = Timesheet::new(&days, 100000, PayRules::default(), Schedule::default());
+        assert_eq!(timesheet.title(), "2025-2026");
+        assert_eq!(timesheet.months().len(), 4);
+        // Two overtime hours at Monday 17th
+        let mut hours = vec![0.0; 30];
+        hours[16] = 2.0;
+        timesheet.set_hours(11, &hours).unwrap();
+
+        // Whole months have 20 working days, only 10 of November and 7 of February are counted
+        let november = timesheet.months()[0].totals();
+        assert_eq!(november.hour_rate, 625.0);
+        assert_eq!(november.norm_hours, 80.0);
+        assert_eq!(november.salary, 50000.0);
+        assert_eq!(november.bonus, 2500.0);
+        let december = timesheet.months()[1].totals();
+        assert_eq!(december.salary, 100000.0);
+        let february = timesheet.months()[3].totals();
+        assert_eq!(february.hour_rate, 625.0);
+        assert_eq!(february.norm_hours, 56.0);
+        assert_eq!(february.salary, 35000.0);
+    }
+
+    #[test]
+    fn vacation_does_not_cut_prorated_salary() {
+        let date = |day| NaiveDate::from_ymd_opt(2026, 3, day).unwrap();
+        let salary = |hired: Option<NaiveDate>, vacation: bool| {
+            let mut days = Days::new(&Period::month(2026, 3).unwrap(), &HashSet::new());
+            days.apply_employment(&Employment {
+                hired,
+                terminated: None,
+            });
+            // Monday 23rd
+            if vacation {
+                days[22] = Day::new(date(23), DayType::Vacation);
+            }
+            let timesheet = Timesheet::new(&days, 100000, PayRules::default(), Schedule::default());
+            timesheet.months()[0].totals().salary
+        };
+
+        // 12 of 22 working days of March 2026 are employed
+        let prorated = 100000.0 * 12.0 / 22.0;
+        assert_eq!(salary(Some(date(16)), false), prorated);
+        assert_eq!(salary(Some(date(16)), true), prorated);
+        assert_eq!(salary(None, true), 100000.0);
+    }
+}
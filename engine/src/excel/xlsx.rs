@@ -0,0 +1,141 @@
+use rust_xlsxwriter::{Format, FormatBorder, workbook::Workbook, worksheet::Worksheet};
+
+use crate::excel::{
+    data::{
+        days::{add_day_cell, add_day_type_rules},
+        headers::add_header_cells,
+        legend::add_legend_cells,
+        names::{define_month_names, define_rule_names},
+        summary::add_summary_cells,
+        total::add_total_cells,
+    },
+    error::Result as EResult,
+    layout::{DayColumn, MONTH_LAYOUT},
+    render::{Renderer, XlsxRenderer},
+    settings::{PrintSetup, Protection, Settings},
+    timesheet::Timesheet,
+};
+
+impl Renderer for XlsxRenderer {
+    fn render(&self, timesheet: &Timesheet, settings: &Settings) -> EResult<Vec<u8>> {
+        // Creating table
+        let mut table = Workbook::new();
+        // Pay rules are shared by all months
+        define_rule_names(&mut table, timesheet.pay_rules(), timesheet.schedule())?;
+        // Year summary goes first, it will be filled after month sheets
+        table.add_worksheet();
+        let mut month_names = Vec::new();
+        for month in timesheet.months() {
+            let month_days = month.days();
+            // Creating Sheet
+            let month_worksheet = table.add_worksheet();
+            // Make worksheet white
+            month_worksheet.set_screen_gridlines(false);
+            // Iterate over days in month chunk
+            for (index, day) in month_days.iter().zip(month.hours()).enumerate() {
+                // Adding day to month sheet
+                add_day_cell(
+                    month_worksheet,
+                    day,
+                    index as u32 + 1,
+                    &settings.theme,
+                    settings.locale,
+                )?;
+            }
+            // Day type dropdown and colours
+            add_day_type_rules(month_worksheet, month.employed_rows(), &settings.theme)?;
+
+            // Adding headers
+            add_header_cells(
+                month_worksheet,
+                month.first_day(),
+                &settings.theme,
+                settings.locale,
+            )?;
+            // Adding colour legend
+            add_legend_cells(month_worksheet, month.prorated(), &settings.theme)?;
+
+            // Add a total block
+            add_total_cells(
+                month_worksheet,
+                month.salary(),
+                month.prorated(),
+                &settings.theme,
+            )?;
+            // Polish worksheet
+            // Do wider border at bottom of days block
+            let days = &MONTH_LAYOUT.days;
+            let border_row = days.last_row(month_days.len() as u8) + 1;
+            let format = Format::new().set_border_top(FormatBorder::Medium);
+            month_worksheet.merge_range(
+                border_row,
+                days.first_col(),
+                border_row,
+                days.last_col(),
+                "",
+                &format,
+            )?;
+            // Autofit columns
+            month_worksheet.autofit();
+            // Make total values column wider
+            month_worksheet.set_column_width(MONTH_LAYOUT.total.value_col(), 12)?;
+            // Make bonus column wider
+            month_worksheet.set_column_width(days.col(DayColumn::Bonus), 10)?;
+            // Make hours column narrower
+            month_worksheet.set_column_width(days.col(DayColumn::Hours), 7.5)?;
+            // Page setup for printing
+            setup_print(month_worksheet, &settings.print)?;
+            // Lock everything except input cells
+            if let Some(protection) = &settings.protection {
+                protect_worksheet(month_worksheet, protection);
+            }
+            // Set month name
+            let month_name = month.name(settings.locale);
+            month_worksheet.set_name(&month_name)?;
+            // Name total cells and day columns for formulas
+            define_month_names(&mut table, &month_name, month_days.len() as u8)?;
+            month_names.push(month_name);
+        }
+        // Fill year summary
+        let summary_worksheet = table.worksheet_from_index(0)?;
+        add_summary_cells(
+            summary_worksheet,
+            &timesheet.title(),
+            &month_names,
+            &settings.theme,
+        )?;
+        if let Some(protection) = &settings.protection {
+            protect_worksheet(summary_worksheet, protection);
+        }
+        // Convert struct to bytes and return it
+        let buf = table.save_to_buffer()?;
+        Ok(buf)
+    }
+}
+
+pub(crate) fn protect_worksheet(worksheet: &mut Worksheet, protection: &Protection) {
+    match &protection.password {
+        Some(password) => worksheet.protect_with_password(password),
+        None => worksheet.protect(),
+    };
+}
+
+fn setup_print(worksheet: &mut Worksheet, print: &PrintSetup) -> EResult<()> {
+    // A4 paper
+    worksheet.set_paper_size(9).set_portrait();
+    if print.fit_to_width {
+        // Zero height means as many pages as needed
+        worksheet.set_print_fit_to_pages(1, 0);
+    }
+    if print.repeat_header {
+        let header_row = MONTH_LAYOUT.days.header(DayColumn::Day).row;
+        worksheet.set_repeat_rows(header_row, header_row)?;
+    }
+    // Employee at left and page number at right, ampersand is escaped in footer
+    let employee = match &print.employee_name {
+        Some(name) => name.replace('&', "&&"),
+        None => "".to_string(),
+    };
+    worksheet.set_footer(format!("&L{}&RСтраница &P из &N", employee));
+    Ok(())
+}
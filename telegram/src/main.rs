@@ -1,10 +1,14 @@
 use anyhow::Result as AResult;
 use chrono::{Datelike, Local};
 use dotenvy::dotenv;
-use engine::excel::get_filled_table;
+use engine::excel::{error::Error, get_filled_table, read_filled_table, settings::Locale};
 use std::{env, net::SocketAddr};
 use teloxide::{
-    dispatching::dialogue::InMemStorage, prelude::*, types::InputFile, update_listeners::webhooks,
+    dispatching::dialogue::InMemStorage,
+    net::Download,
+    prelude::*,
+    types::{Document, InputFile},
+    update_listeners::webhooks,
 };
 
 type UserDialogue = Dialogue<DState, InMemStorage<DState>>;
@@ -92,12 +96,29 @@ async fn salary(bot: Bot, msg: Message) -> AResult<()> {
             .await?;
         Ok(())
     };
+    // Filled table sent back, reply with payouts
+    if let Some(document) = msg.document() {
+        return filled_table(&bot, &msg, document).await;
+    }
     match msg.text() {
         Some(text) => {
             let salary = text.parse::<u32>().ok();
             match salary {
                 Some(s) => {
-                    let table = get_filled_table(s).await?;
+                    let table = match get_filled_table(s).await {
+                        Ok(table) => table,
+                        // Calendar source is down, user can try again later
+                        Err(Error::CalendarFetch(err)) => {
+                            log::warn!("Не удалось загрузить календарь: {err}");
+                            bot.send_message(
+                                msg.chat.id,
+                                "Не удалось загрузить календарь праздников! Попробуйте позже.",
+                            )
+                            .await?;
+                            return Ok(());
+                        }
+                        Err(err) => return Err(err.into()),
+                    };
                     bot.send_document(
                         msg.chat.id,
                         InputFile::memory(table)
@@ -112,3 +133,33 @@ async fn salary(bot: Bot, msg: Message) -> AResult<()> {
     }
     Ok(())
 }
+
+async fn filled_table(bot: &Bot, msg: &Message, document: &Document) -> AResult<()> {
+    let file = bot.get_file(document.file.id.clone()).await?;
+    let mut table = Vec::new();
+    bot.download_file(&file.path, &mut table).await?;
+    let timesheet = match read_filled_table(&table) {
+        Ok(timesheet) => timesheet,
+        Err(err) => {
+            log::warn!("Не удалось прочитать табель: {err}");
+            bot.send_message(
+                msg.chat.id,
+                "Не удалось прочитать табель! Пришлите файл из бота.",
+            )
+            .await?;
+            return Ok(());
+        }
+    };
+    let mut text = format!("Табель за {}:\n", timesheet.title());
+    for month in timesheet.months() {
+        let totals = month.totals();
+        text.push_str(&format!(
+            "{}: доплата {:.2} ₽, к получению {:.2} ₽\n",
+            month.title(Locale::Ru),
+            totals.bonus,
+            totals.payout
+        ));
+    }
+    bot.send_message(msg.chat.id, text).await?;
+    Ok(())
+}
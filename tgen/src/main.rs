@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use anyhow::Result as AResult;
+use clap::Parser;
+use engine::excel::{
+    builder::TimesheetBuilder,
+    settings::{CalendarSource, Locale, OutputFormat},
+};
+
+/// Генерация табеля рабочего времени в файл
+#[derive(Parser)]
+#[command(name = "tgen", version)]
+struct Args {
+    /// Оклад в месяц, ячейка остаётся пустой если 0
+    #[arg(short, long, default_value_t = 0)]
+    salary: u32,
+    /// Год табеля, по умолчанию последний год календаря
+    #[arg(short, long)]
+    year: Option<i32>,
+    /// Путь к файлу, по умолчанию timesheet.<расширение> в текущей папке
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Календарь праздников: ссылка или путь к JSON файлу
+    #[arg(short, long)]
+    calendar: Option<String>,
+    /// Формат файла: xlsx, ods, pdf, json или t13
+    #[arg(short, long, default_value = "xlsx")]
+    format: OutputFormat,
+    /// Язык табеля, пока только ru
+    #[arg(short, long, default_value = "ru")]
+    locale: Locale,
+}
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+    // Non-zero exit code lets cron and scripts notice failure
+    if let Err(error) = run(args).await {
+        eprintln!("Ошибка: {error}");
+        std::process::exit(1);
+    }
+}
+
+async fn run(args: Args) -> AResult<()> {
+    // Links are downloaded, anything else is a local file
+    let calendar = match args.calendar {
+        Some(url) if url.starts_with("http://") || url.starts_with("https://") => {
+            CalendarSource::Url(url)
+        }
+        Some(path) => CalendarSource::File(path.into()),
+        None => CalendarSource::default(),
+    };
+    let mut builder = TimesheetBuilder::new(args.salary)
+        .calendar(calendar)
+        .format(args.format)
+        .locale(args.locale);
+    if let Some(year) = args.year {
+        builder = builder.year(year);
+    }
+    let table = builder.build().await?;
+    let output = args
+        .output
+        .unwrap_or_else(|| format!("timesheet.{}", args.format.extension()).into());
+    std::fs::write(&output, table)?;
+    // Path goes to stdout for scripts
+    println!("{}", output.display());
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use chrono::NaiveDate;
+use engine::excel::{error::Error, options::TimesheetOptions, settings::CalendarSource};
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+// Same format as default calendar, page downloads or bundles it itself
+#[derive(Deserialize)]
+struct Calendar {
+    holidays: Vec<NaiveDate>,
+}
+
+/// Timesheet file bytes, xlsx by default. `options` is an object like API request body,
+/// `calendar` is JSON text with `holidays` array of "year-month-day" dates
+#[wasm_bindgen]
+pub async fn generate(options: JsValue, calendar: String) -> Result<Vec<u8>, JsError> {
+    let options: TimesheetOptions = serde_wasm_bindgen::from_value(options)
+        .map_err(|e| Error::InvalidOptions(e.to_string()))?;
+    let calendar: Calendar =
+        serde_json::from_str(&calendar).map_err(|e| Error::CalendarParse(e.into()))?;
+    let builder = options
+        .builder()?
+        .calendar(CalendarSource::Holidays(calendar.holidays));
+    Ok(builder.build().await?)
+}